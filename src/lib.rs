@@ -1,4 +1,10 @@
 pub mod libs;
 
-pub use libs::handlers::client::Client;
+pub use libs::config::{CacheConfig, ClientConfig};
+pub use libs::error::Error;
+pub use libs::handlers::client::{Client, ClientBuilder};
+pub use libs::handlers::{
+    Auth, Download, ImageDownload, Join, MediaType, MovieSort, RateLimiter, RetryPolicy, ShowSort,
+    TimeWindow,
+};
 pub use libs::models;
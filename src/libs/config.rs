@@ -0,0 +1,172 @@
+use serde::{Deserialize, Serialize};
+use std::env;
+use std::fs;
+use std::path::{Path, PathBuf};
+use std::time::Duration;
+
+use crate::{Auth, Client, ClientBuilder, Error};
+
+/// Settings for caching data from tmdb
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct CacheConfig {
+    /// The directory to store cached files in
+    pub dir: Option<PathBuf>,
+    /// How many seconds cached data stays fresh for
+    pub ttl: Option<u64>,
+}
+
+/// Settings for building a [`Client`] from a file or the environment
+///
+/// Files can be either TOML or JSON and any of these settings can be
+/// overridden with environment variables.
+///
+/// | Setting             | Environment variable     |
+/// |---------------------|--------------------------|
+/// | `token`             | `TMDB_TOKEN`             |
+/// | `read_access_token` | `TMDB_READ_ACCESS_TOKEN` |
+/// | `host`              | `TMDB_HOST`              |
+/// | `language`          | `TMDB_LANGUAGE`          |
+/// | `region`            | `TMDB_REGION`            |
+/// | `timeout`           | `TMDB_TIMEOUT`           |
+/// | `cache.dir`         | `TMDB_CACHE_DIR`         |
+/// | `cache.ttl`         | `TMDB_CACHE_TTL`         |
+///
+/// # Examples
+///
+/// ```toml
+/// token = "TMDB_TOKEN"
+/// language = "en-US"
+/// region = "US"
+/// timeout = 10
+///
+/// [cache]
+/// dir = "/var/cache/tmdb"
+/// ttl = 86400
+/// ```
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+#[serde(default)]
+pub struct ClientConfig {
+    /// The v3 api key to authenticate with
+    pub token: Option<String>,
+    /// The v4 read access token to authenticate with if no api key is set
+    pub read_access_token: Option<String>,
+    /// The host/url tmdb is at
+    pub host: Option<String>,
+    /// The language to request data in by default
+    pub language: Option<String>,
+    /// The region to request data for by default
+    pub region: Option<String>,
+    /// How many seconds to wait for a request to complete
+    pub timeout: Option<u64>,
+    /// Settings for caching data from tmdb
+    pub cache: CacheConfig,
+}
+
+impl ClientConfig {
+    /// Loads settings from a TOML or JSON file
+    ///
+    /// Files ending in `.json` are parsed as JSON and all others as TOML.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the file to load
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        let path = path.as_ref();
+        let data = fs::read_to_string(path)
+            .map_err(|e| Error::Config(format!("Failed to read {} with {}", path.display(), e)))?;
+        // parse our config based on its extension
+        let is_json = path.extension().and_then(|ext| ext.to_str()) == Some("json");
+        let parsed = if is_json {
+            serde_json::from_str(&data).map_err(|e| e.to_string())
+        } else {
+            toml::from_str(&data).map_err(|e| e.to_string())
+        };
+        parsed.map_err(|e| Error::Config(format!("Failed to parse {} with {}", path.display(), e)))
+    }
+
+    /// Overrides these settings with any that are set in the environment
+    pub fn with_env(mut self) -> Result<Self, Error> {
+        // credentials from the environment replace any from a file
+        let token = env_var("TMDB_TOKEN");
+        let read_access_token = env_var("TMDB_READ_ACCESS_TOKEN");
+        if token.is_some() || read_access_token.is_some() {
+            self.token = token;
+            self.read_access_token = read_access_token;
+        }
+        if let Some(host) = env_var("TMDB_HOST") {
+            self.host = Some(host);
+        }
+        if let Some(language) = env_var("TMDB_LANGUAGE") {
+            self.language = Some(language);
+        }
+        if let Some(region) = env_var("TMDB_REGION") {
+            self.region = Some(region);
+        }
+        if let Some(timeout) = env_var("TMDB_TIMEOUT") {
+            self.timeout = Some(parse_env("TMDB_TIMEOUT", &timeout)?);
+        }
+        if let Some(dir) = env_var("TMDB_CACHE_DIR") {
+            self.cache.dir = Some(PathBuf::from(dir));
+        }
+        if let Some(ttl) = env_var("TMDB_CACHE_TTL") {
+            self.cache.ttl = Some(parse_env("TMDB_CACHE_TTL", &ttl)?);
+        }
+        Ok(self)
+    }
+
+    /// Turns these settings into a client builder so they can be further customized
+    pub fn builder(self) -> Result<ClientBuilder, Error> {
+        // an api key takes precedence over a read access token
+        let auth = match (self.token, self.read_access_token) {
+            (Some(token), _) => Auth::ApiKey(token),
+            (None, Some(token)) => Auth::Bearer(token),
+            (None, None) => {
+                return Err(Error::Config(
+                    "No TMDB_TOKEN or TMDB_READ_ACCESS_TOKEN was set".into(),
+                ))
+            }
+        };
+        let mut builder = ClientBuilder::new(auth).cache(self.cache);
+        // add our optional settings if they were set
+        if let Some(host) = self.host {
+            builder = builder.host(host);
+        }
+        if let Some(language) = self.language {
+            builder = builder.language(language);
+        }
+        if let Some(region) = self.region {
+            builder = builder.region(region);
+        }
+        if let Some(timeout) = self.timeout {
+            builder = builder.timeout(Duration::from_secs(timeout));
+        }
+        Ok(builder)
+    }
+
+    /// Builds a client from these settings
+    pub fn build(self) -> Result<Client, Error> {
+        self.builder()?.build()
+    }
+}
+
+/// Get an environment variable if its set and not empty
+///
+/// # Arguments
+///
+/// * `key` - The environment variable to get
+fn env_var(key: &str) -> Option<String> {
+    env::var(key).ok().filter(|value| !value.is_empty())
+}
+
+/// Parse a numeric environment variable
+///
+/// # Arguments
+///
+/// * `key` - The environment variable being parsed
+/// * `value` - The value to parse
+fn parse_env(key: &str, value: &str) -> Result<u64, Error> {
+    value
+        .parse()
+        .map_err(|e| Error::Config(format!("Failed to parse {} with {}", key, e)))
+}
@@ -0,0 +1,206 @@
+use chrono::prelude::*;
+use reqwest::header::{HeaderMap, RETRY_AFTER};
+use reqwest::{Response, StatusCode};
+use serde::Deserialize;
+use std::fmt;
+use std::time::Duration;
+
+/// The max number of characters of a response body to keep in an error
+const SNIPPET_LEN: usize = 256;
+
+/// The error body tmdb returns when a request fails
+#[derive(Deserialize, Debug)]
+struct TmdbError {
+    /// The tmdb specific error code
+    status_code: Option<i64>,
+    /// A message describing this error
+    status_message: Option<String>,
+}
+
+/// An error from talking to tmdb
+///
+/// # Examples
+///
+/// ```
+/// use tmdb_cli::{Client, Error};
+///
+/// # #[tokio::main]
+/// # async fn main() {
+/// // build a client
+/// let tmdb = Client::from_env();
+/// // tell a missing movie apart from other failures
+/// match tmdb.movies.details(0).await {
+///   Ok(details) => println!("found {}", details.title),
+///   Err(Error::NotFound { .. }) => println!("no such movie"),
+///   Err(e) => panic!("failed to get movie with {}", e),
+/// }
+/// # }
+/// ```
+#[derive(Debug)]
+pub enum Error {
+    /// The requested resource does not exist
+    NotFound {
+        /// The message tmdb returned
+        message: String,
+    },
+    /// The token used was invalid, revoked, or lacks access to this resource
+    Unauthorized {
+        /// The tmdb specific error code
+        tmdb_code: Option<i64>,
+        /// The message tmdb returned
+        message: String,
+    },
+    /// Tmdb is throttling our requests
+    RateLimited {
+        /// How long tmdb asked us to wait before retrying
+        retry_after: Option<Duration>,
+    },
+    /// The response from tmdb could not be deserialized
+    Deserialize {
+        /// The path to the field that failed to deserialize
+        path: String,
+        /// The start of the body that failed to deserialize
+        snippet: String,
+        /// The underlying deserialization error
+        source: serde_json::Error,
+    },
+    /// A request could not be sent or its response could not be read
+    Transport(reqwest::Error),
+    /// A client could not be configured
+    Config(String),
+    /// A file could not be read or written
+    Io(std::io::Error),
+    /// A cursor was asked to navigate to a page that does not exist
+    PageOutOfRange {
+        /// The page that was requested
+        page: u64,
+        /// The total number of pages that exist
+        total_pages: u64,
+    },
+    /// Tmdb returned an error that has no dedicated variant
+    Api {
+        /// The http status code tmdb returned
+        status: u16,
+        /// The tmdb specific error code
+        tmdb_code: Option<i64>,
+        /// The message tmdb returned
+        message: String,
+    },
+}
+
+impl Error {
+    /// Builds an error from a failed response
+    ///
+    /// # Arguments
+    ///
+    /// * `resp` - The response with a non success status code
+    pub(crate) async fn from_response(resp: Response) -> Self {
+        let status = resp.status();
+        // rate limits are determined by headers so grab those before consuming the body
+        if status == StatusCode::TOO_MANY_REQUESTS {
+            let retry_after = retry_after(resp.headers());
+            return Error::RateLimited { retry_after };
+        }
+        // try to parse the tmdb error body but fall back to the status if we can't
+        let body = resp.json::<TmdbError>().await.ok();
+        let tmdb_code = body.as_ref().and_then(|body| body.status_code);
+        let message = body
+            .and_then(|body| body.status_message)
+            .unwrap_or_else(|| status.to_string());
+        match status {
+            StatusCode::NOT_FOUND => Error::NotFound { message },
+            StatusCode::UNAUTHORIZED => Error::Unauthorized { tmdb_code, message },
+            _ => Error::Api {
+                status: status.as_u16(),
+                tmdb_code,
+                message,
+            },
+        }
+    }
+
+    /// Deserializes a response body, tracking the path to any field that fails
+    ///
+    /// # Arguments
+    ///
+    /// * `body` - The body to deserialize
+    pub(crate) fn deserialize<T: serde::de::DeserializeOwned>(body: &[u8]) -> Result<T, Self> {
+        let deserializer = &mut serde_json::Deserializer::from_slice(body);
+        serde_path_to_error::deserialize(deserializer).map_err(|err| {
+            // keep the start of the body to make debugging easier
+            let snippet = String::from_utf8_lossy(body)
+                .chars()
+                .take(SNIPPET_LEN)
+                .collect();
+            Error::Deserialize {
+                path: err.path().to_string(),
+                snippet,
+                source: err.into_inner(),
+            }
+        })
+    }
+}
+
+/// Get how long tmdb asked us to wait from a Retry-After header
+///
+/// # Arguments
+///
+/// * `headers` - The headers to check
+pub(crate) fn retry_after(headers: &HeaderMap) -> Option<Duration> {
+    let value = headers.get(RETRY_AFTER)?.to_str().ok()?;
+    // this header can either be a number of seconds or an http date
+    if let Ok(secs) = value.trim().parse::<u64>() {
+        return Some(Duration::from_secs(secs));
+    }
+    let date = DateTime::parse_from_rfc2822(value).ok()?;
+    // dates in the past mean we can retry immediately
+    let wait = date.with_timezone(&Utc) - Utc::now();
+    Some(wait.to_std().unwrap_or_default())
+}
+
+impl fmt::Display for Error {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            Error::NotFound { message } => write!(f, "Not found: {}", message),
+            Error::Unauthorized { message, .. } => write!(f, "Unauthorized: {}", message),
+            Error::RateLimited {
+                retry_after: Some(wait),
+            } => write!(f, "Rate limited: retry after {:?}", wait),
+            Error::RateLimited { retry_after: None } => write!(f, "Rate limited"),
+            Error::Deserialize { path, source, .. } => {
+                write!(f, "Failed to deserialize {} with {}", path, source)
+            }
+            Error::Transport(err) => write!(f, "Transport error: {}", err),
+            Error::Config(msg) => write!(f, "Config error: {}", msg),
+            Error::Io(err) => write!(f, "IO error: {}", err),
+            Error::PageOutOfRange { page, total_pages } => {
+                write!(f, "Page {} is out of range 1-{}", page, total_pages)
+            }
+            Error::Api {
+                status, message, ..
+            } => write!(f, "Tmdb returned {}: {}", status, message),
+        }
+    }
+}
+
+impl std::error::Error for Error {
+    fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+        match self {
+            Error::Deserialize { source, .. } => Some(source),
+            Error::Transport(err) => Some(err),
+            Error::Io(err) => Some(err),
+            _ => None,
+        }
+    }
+}
+
+impl From<reqwest::Error> for Error {
+    fn from(err: reqwest::Error) -> Self {
+        Error::Transport(err)
+    }
+}
+
+impl From<std::io::Error> for Error {
+    fn from(err: std::io::Error) -> Self {
+        Error::Io(err)
+    }
+}
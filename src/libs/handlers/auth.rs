@@ -0,0 +1,57 @@
+use std::fmt;
+
+/// How to authenticate to tmdb
+///
+/// # Examples
+///
+/// ```
+/// use tmdb_cli::{Auth, Client};
+///
+/// // authenticate with a v4 read access token instead of an api key
+/// let tmdb = Client::builder(Auth::Bearer("TMDB_READ_ACCESS_TOKEN".into()))
+///   .build()
+///   .unwrap();
+/// ```
+#[derive(Clone)]
+pub enum Auth {
+    /// A v3 api key sent as the `api_key` query param
+    ApiKey(String),
+    /// A v4 read access token sent in the `Authorization` header
+    Bearer(String),
+}
+
+impl Auth {
+    /// Adds our credentials to a request
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The request to authenticate
+    pub(crate) fn apply(&self, req: reqwest::RequestBuilder) -> reqwest::RequestBuilder {
+        match self {
+            Auth::ApiKey(key) => req.query(&[("api_key", key)]),
+            Auth::Bearer(token) => req.bearer_auth(token),
+        }
+    }
+}
+
+impl From<String> for Auth {
+    fn from(key: String) -> Self {
+        Auth::ApiKey(key)
+    }
+}
+
+impl From<&str> for Auth {
+    fn from(key: &str) -> Self {
+        Auth::ApiKey(key.to_owned())
+    }
+}
+
+impl fmt::Debug for Auth {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        // never leak credentials into logs
+        match self {
+            Auth::ApiKey(_) => write!(f, "ApiKey(<redacted>)"),
+            Auth::Bearer(_) => write!(f, "Bearer(<redacted>)"),
+        }
+    }
+}
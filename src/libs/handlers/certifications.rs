@@ -0,0 +1,90 @@
+use std::collections::HashMap;
+
+use super::core::Transport;
+use crate::libs::models::{Certification, CertificationList, CountryCode};
+use crate::{get, Error};
+
+/// Handlers for certification routes
+#[derive(Clone)]
+pub struct Certifications {
+    /// The shared connection to tmdb
+    transport: Transport,
+}
+
+impl Certifications {
+    /// Create a new Certifications handler
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    pub(crate) fn new(transport: &Transport) -> Self {
+        // build certifications handler
+        Certifications {
+            transport: transport.clone(),
+        }
+    }
+
+    /// Get the certifications that movies can be given in each country
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // only allow movies rated PG or lower in the US
+    /// let certs = tmdb.certifications.movie().await.unwrap();
+    /// let max = certs["US"].iter().find(|cert| cert.certification == "PG").unwrap();
+    /// let releases = tmdb.movies.release_dates(157336).await.unwrap();
+    /// let allowed = releases
+    ///   .certification("US")
+    ///   .and_then(|rating| certs["US"].iter().find(|cert| cert.certification == rating))
+    ///   .map(|cert| cert.order <= max.order)
+    ///   .unwrap_or(false);
+    /// # assert!(!allowed)
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn movie(&self) -> Result<HashMap<CountryCode, Vec<Certification>>, Error> {
+        self.list("movie").await
+    }
+
+    /// Get the certifications that TV shows can be given in each country
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the ratings shows can be given
+    /// let certs = tmdb.certifications.tv().await;
+    /// # assert!(certs.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn tv(&self) -> Result<HashMap<CountryCode, Vec<Certification>>, Error> {
+        self.list("tv").await
+    }
+
+    /// Get the certifications that a kind of media can be given in each country
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of media to get certifications for
+    async fn list(&self, kind: &str) -> Result<HashMap<CountryCode, Vec<Certification>>, Error> {
+        // build url to query
+        let url = format!("{}/3/certification/{}/list", &self.transport.host, kind);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a CertificationList object from the response
+        let list: CertificationList = get!(self, req)?;
+        Ok(list.certifications)
+    }
+}
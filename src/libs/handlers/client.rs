@@ -1,13 +1,72 @@
-use std::env;
+use futures::stream::{self, StreamExt};
+use percent_encoding::{utf8_percent_encode, AsciiSet, CONTROLS};
+use reqwest::header::{HeaderMap, HeaderName, HeaderValue};
+use reqwest::Proxy;
+use std::path::{Path, PathBuf};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
 
-use super::{movies::Movies, tv::Tv};
+use super::auth::Auth;
+use super::certifications::Certifications;
+use super::collections::Collections;
+use super::companies::Companies;
+use super::core::{Defaults, Transport};
+use super::download::{self, Download, EtagIndex, ImageDownload};
+use super::limiter::RateLimiter;
+use super::networks::Networks;
+use super::retry::RetryPolicy;
+use super::search::MultiSearch;
+use super::{movies::Movies, people::People, providers::Providers, trending::Trending, tv::Tv};
+use crate::libs::config::{CacheConfig, ClientConfig};
+use crate::libs::models::{Configuration, ExternalSource, FindResult, ImageSize};
+use crate::{get, Error};
+
+/// The characters to escape in a single url path segment
+const PATH_SEGMENT: &AsciiSet = &CONTROLS
+    .add(b' ')
+    .add(b'"')
+    .add(b'#')
+    .add(b'%')
+    .add(b'/')
+    .add(b'<')
+    .add(b'>')
+    .add(b'?')
+    .add(b'`')
+    .add(b'{')
+    .add(b'}');
+
+/// The default host to reach tmdb at
+const DEFAULT_HOST: &str = "https://api.themoviedb.org";
+
+/// How many seconds to cache the tmdb configuration for if no ttl is set
+const DEFAULT_CONFIGURATION_TTL: u64 = 60 * 60 * 24;
 
 /// A TMDB client
 pub struct Client {
     /// Movie focused routes handlers
     pub movies: Movies,
     /// TV focused routes handlers
-    pub tv: Tv
+    pub tv: Tv,
+    /// People focused routes handlers
+    pub people: People,
+    /// Collection focused routes handlers
+    pub collections: Collections,
+    /// Company focused routes handlers
+    pub companies: Companies,
+    /// TV network focused routes handlers
+    pub networks: Networks,
+    /// Trending routes handlers
+    pub trending: Trending,
+    /// Watch provider routes handlers
+    pub providers: Providers,
+    /// Certification routes handlers
+    pub certifications: Certifications,
+    /// The shared connection to tmdb
+    transport: Transport,
+    /// Settings for caching data from tmdb
+    cache: CacheConfig,
+    /// The tmdb configuration and when it was retrieved
+    configuration: Mutex<Option<(Instant, Configuration)>>,
 }
 
 impl Client {
@@ -25,15 +84,37 @@ impl Client {
     /// let tmdb = Client::new("TMDB_TOKEN".into());
     /// ```
     pub fn new(token: String) -> Self {
-        // default tmdb host
-        let host = "https://api.themoviedb.org";
-        let movies = Movies::new(host, &token);
-        let tv = Tv::new(host, &token);
-        Client { movies, tv }
+        ClientBuilder::new(token)
+            .build()
+            .expect("failed to build client")
+    }
+
+    /// Starts building a client with custom settings
+    ///
+    /// # Arguments
+    ///
+    /// # `auth` - The api key or [`Auth`] to authenticate to tmdb with
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use std::time::Duration;
+    /// use tmdb_cli::Client;
+    ///
+    /// let tmdb = Client::builder("TMDB_TOKEN")
+    ///   .host("http://127.0.0.1:8080")
+    ///   .timeout(Duration::from_secs(10))
+    ///   .build()
+    ///   .unwrap();
+    /// ```
+    pub fn builder<A: Into<Auth>>(auth: A) -> ClientBuilder {
+        ClientBuilder::new(auth)
     }
 
     /// Creates a new client with a token pulled from the environment
     ///
+    /// This panics if no token is set, use [`Client::try_from_env`] to handle that instead.
+    ///
     /// # Examples
     ///
     /// ```
@@ -42,11 +123,526 @@ impl Client {
     /// let tmdb = Client::from_env();
     /// ```
     pub fn from_env() -> Self {
-        // get the tmdb token from our environment variables
-        let token = match env::var("TMDB_TOKEN") {
-            Ok(token) => token,
-            Err(e) => panic!("Failed to get token from environment with {}", e),
+        match Self::try_from_env() {
+            Ok(tmdb) => tmdb,
+            Err(e) => panic!("Failed to build client from environment with {}", e),
+        }
+    }
+
+    /// Tries to create a new client with settings pulled from the environment
+    ///
+    /// An api key is read from `TMDB_TOKEN` and if that is not set a v4 read
+    /// access token is read from `TMDB_READ_ACCESS_TOKEN`. See [`ClientConfig`]
+    /// for the other settings that can be set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmdb_cli::Client;
+    ///
+    /// match Client::try_from_env() {
+    ///   Ok(tmdb) => println!("built a client"),
+    ///   Err(e) => println!("failed to build a client with {}", e),
+    /// }
+    /// ```
+    pub fn try_from_env() -> Result<Self, Error> {
+        ClientConfig::default().with_env()?.build()
+    }
+
+    /// Creates a new client from a TOML or JSON config file
+    ///
+    /// Any settings set in the environment override those in the file.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the config file to load
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tmdb_cli::Client;
+    ///
+    /// let tmdb = Client::from_file("tmdb.toml").unwrap();
+    /// ```
+    pub fn from_file<P: AsRef<Path>>(path: P) -> Result<Self, Error> {
+        ClientConfig::from_file(path)?.with_env()?.build()
+    }
+
+    /// Search for movies, TV shows and people at once
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query to use when searching
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // search for anything matching our query
+    /// let search = tmdb.search_multi("Red")
+    ///   .language("en-US")
+    ///   .exec()
+    ///   .await
+    ///   .unwrap();
+    /// // print the title of each result regardless of its type
+    /// for item in &search.results {
+    ///   println!("{} ({:?})", item.title(), item.year());
+    /// }
+    /// # }
+    /// ```
+    pub fn search_multi<T: Into<String>>(&self, query: T) -> MultiSearch<'_> {
+        MultiSearch::new(&self.transport, query.into())
+    }
+
+    /// Find movies, shows, seasons, episodes and people by an ID from another database
+    ///
+    /// # Arguments
+    ///
+    /// * `external_id` - The ID to find
+    /// * `source` - The database this ID is from
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmdb_cli::models::ExternalSource;
+    /// use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // find a movie by its imdb id
+    /// let found = tmdb.find("tt0816692", ExternalSource::ImdbId).await.unwrap();
+    /// # assert_eq!(found.movie_results[0].id, 157336)
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn find(
+        &self,
+        external_id: &str,
+        source: ExternalSource,
+    ) -> Result<FindResult, Error> {
+        // escape ids like social media handles so they stay a single path segment
+        let id = utf8_percent_encode(external_id, PATH_SEGMENT);
+        // build url to query
+        let url = format!("{}/3/find/{}", &self.transport.host, id);
+        // build a request using the our token and query
+        let params = vec![("external_source".into(), source.as_str().into())];
+        let req = self.transport.get_with(&url, params, Defaults::Language);
+        // send request and build a FindResult object from the response
+        get!(self, req)
+    }
+
+    /// Get the current tmdb configuration used to build image urls
+    ///
+    /// The configuration is cached on this client for the cache ttl or a day if no ttl is set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the current configuration
+    /// let config = tmdb.configuration().await.unwrap();
+    /// println!("images are at {}", config.images.secure_base_url);
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn configuration(&self) -> Result<Configuration, Error> {
+        self.load_configuration().await
+    }
+
+    /// Get the current tmdb configuration from our cache or tmdb
+    async fn load_configuration(&self) -> Result<Configuration, Error> {
+        // use our cached configuration if its still fresh
+        if let Some(config) = self.cached_configuration() {
+            return Ok(config);
+        }
+        // build url to query
+        let url = format!("{}/3/configuration", &self.transport.host);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a Configuration object from the response
+        let config: Configuration = get!(self, req)?;
+        // cache this configuration for later calls
+        let mut cached = self.configuration.lock().unwrap_or_else(|e| e.into_inner());
+        *cached = Some((Instant::now(), config.clone()));
+        Ok(config)
+    }
+
+    /// Get our cached configuration if it hasn't expired yet
+    fn cached_configuration(&self) -> Option<Configuration> {
+        let ttl = self.cache.ttl.unwrap_or(DEFAULT_CONFIGURATION_TTL);
+        let cached = self.configuration.lock().unwrap_or_else(|e| e.into_inner());
+        match &*cached {
+            Some((retrieved, config)) if retrieved.elapsed() < Duration::from_secs(ttl) => {
+                Some(config.clone())
+            }
+            _ => None,
+        }
+    }
+
+    /// Downloads an image to disk
+    ///
+    /// Relative destinations are placed in the cache directory if one is set. The
+    /// image is not downloaded again if the copy already on disk has the same ETag
+    /// or size as the one tmdb has. ETags are only kept if a cache directory is set
+    /// and are stored in a single `image-etags.json` file inside of it.
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the image such as a `poster_path`
+    /// * `size` - The size of the image to download
+    /// * `dest` - Where to write this image to
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// pub use tmdb_cli::Client;
+    /// use tmdb_cli::models::PosterSize;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // download the poster for a movie next to its video file
+    /// let movie = tmdb.movies.details(157336).await.unwrap();
+    /// if let Some(poster) = &movie.poster_path {
+    ///   tmdb.download_image(poster, PosterSize::W500, "/media/Interstellar/poster.jpg")
+    ///     .await
+    ///     .unwrap();
+    /// }
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn download_image<S: ImageSize, P: AsRef<Path>>(
+        &self,
+        path: &str,
+        size: S,
+        dest: P,
+    ) -> Result<Download, Error> {
+        // build the url to download this image from
+        let config = self.load_configuration().await?;
+        let url = config.image_url(path, size);
+        let index = EtagIndex::load(&self.cache).await;
+        let dest = self.image_dest(dest.as_ref());
+        let download = download::download(&self.transport, &index, &url, dest).await;
+        index.save().await?;
+        download
+    }
+
+    /// Downloads many images to disk at once
+    ///
+    /// Each image is downloaded just like [`Client::download_image`] and the result
+    /// of each download is returned in the same order the images were given in.
+    ///
+    /// # Arguments
+    ///
+    /// * `images` - The images to download
+    /// * `concurrency` - The max number of images to download at once
+    ///
+    /// # Examples
+    ///
+    /// ```no_run
+    /// use tmdb_cli::models::{BackdropSize, PosterSize};
+    /// use tmdb_cli::{Client, ImageDownload};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // download the artwork for a movie
+    /// let movie = tmdb.movies.details(157336).await.unwrap();
+    /// let mut images = Vec::new();
+    /// if let Some(poster) = &movie.poster_path {
+    ///   images.push(ImageDownload::new(poster, PosterSize::Original, "Interstellar/poster.jpg"));
+    /// }
+    /// if let Some(backdrop) = &movie.backdrop_path {
+    ///   images.push(ImageDownload::new(backdrop, BackdropSize::Original, "Interstellar/fanart.jpg"));
+    /// }
+    /// for download in tmdb.download_images(images, 4).await.unwrap() {
+    ///   println!("{:?}", download.unwrap().path);
+    /// }
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn download_images<I: IntoIterator<Item = ImageDownload>>(
+        &self,
+        images: I,
+        concurrency: usize,
+    ) -> Result<Vec<Result<Download, Error>>, Error> {
+        // build the urls to download these images from
+        let config = self.load_configuration().await?;
+        // share one etag index across the whole batch
+        let index = EtagIndex::load(&self.cache).await;
+        let index = &index;
+        let downloads = images.into_iter().map(|image| {
+            let url = config.sized_image_url(&image.path, image.size);
+            let dest = self.image_dest(&image.dest);
+            async move { download::download(&self.transport, index, &url, dest).await }
+        });
+        // download our images with at most concurrency downloads at once
+        let results = stream::iter(downloads)
+            .buffered(concurrency.max(1))
+            .collect()
+            .await;
+        index.save().await?;
+        Ok(results)
+    }
+
+    /// Get where to write an image placing relative paths in our cache directory
+    ///
+    /// # Arguments
+    ///
+    /// * `dest` - The destination that was requested
+    fn image_dest(&self, dest: &Path) -> PathBuf {
+        match &self.cache.dir {
+            Some(dir) if dest.is_relative() => dir.join(dest),
+            _ => dest.to_path_buf(),
+        }
+    }
+
+    /// Get the settings for caching data from tmdb
+    pub fn cache(&self) -> &CacheConfig {
+        &self.cache
+    }
+}
+
+/// Builds a [`Client`] with custom connection settings
+///
+/// Every handler and cursor created from the built client shares a single
+/// connection pool.
+pub struct ClientBuilder {
+    /// How to authenticate to tmdb
+    auth: Auth,
+    /// The URL/ip to reach tmdb at
+    host: String,
+    /// How long to wait for a request to complete
+    timeout: Duration,
+    /// The proxy to send requests through
+    proxy: Option<Proxy>,
+    /// The user agent to send with each request
+    user_agent: Option<String>,
+    /// Headers to send with each request
+    headers: HeaderMap,
+    /// How failed requests should be retried
+    retry: RetryPolicy,
+    /// The rate limiter every request must pass through
+    limiter: Option<RateLimiter>,
+    /// The language to request data in by default
+    language: Option<String>,
+    /// The region to request data for by default
+    region: Option<String>,
+    /// Settings for caching data from tmdb
+    cache: CacheConfig,
+}
+
+impl ClientBuilder {
+    /// Creates a new client builder with the default settings
+    ///
+    /// # Arguments
+    ///
+    /// # `auth` - The api key or [`Auth`] to authenticate to tmdb with
+    pub fn new<A: Into<Auth>>(auth: A) -> Self {
+        ClientBuilder {
+            auth: auth.into(),
+            host: DEFAULT_HOST.to_owned(),
+            timeout: Duration::from_secs(30),
+            proxy: None,
+            user_agent: None,
+            headers: HeaderMap::new(),
+            retry: RetryPolicy::default(),
+            limiter: None,
+            language: None,
+            region: None,
+            cache: CacheConfig::default(),
+        }
+    }
+
+    /// Sets how to authenticate to tmdb
+    ///
+    /// # Arguments
+    ///
+    /// * `auth` - The credentials to use
+    pub fn auth(mut self, auth: Auth) -> Self {
+        self.auth = auth;
+        self
+    }
+
+    /// Sets the host/url to reach tmdb at
+    ///
+    /// This is useful for pointing the client at a mock server or caching proxy.
+    ///
+    /// # Arguments
+    ///
+    /// * `host` - The host/url tmdb is at
+    pub fn host<T: Into<String>>(mut self, host: T) -> Self {
+        // strip any trailing slashes so our routes can be appended cleanly
+        self.host = host.into().trim_end_matches('/').to_owned();
+        self
+    }
+
+    /// Sets how long to wait for a request to complete
+    ///
+    /// # Arguments
+    ///
+    /// * `timeout` - The timeout to use for each request
+    pub fn timeout(mut self, timeout: Duration) -> Self {
+        self.timeout = timeout;
+        self
+    }
+
+    /// Sets a proxy to send all requests through
+    ///
+    /// # Arguments
+    ///
+    /// * `proxy` - The proxy to use
+    pub fn proxy(mut self, proxy: Proxy) -> Self {
+        self.proxy = Some(proxy);
+        self
+    }
+
+    /// Sets the user agent to send with each request
+    ///
+    /// # Arguments
+    ///
+    /// * `user_agent` - The user agent to use
+    pub fn user_agent<T: Into<String>>(mut self, user_agent: T) -> Self {
+        self.user_agent = Some(user_agent.into());
+        self
+    }
+
+    /// Adds a header to send with each request
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The name of the header
+    /// * `value` - The value of the header
+    pub fn header(mut self, key: HeaderName, value: HeaderValue) -> Self {
+        self.headers.insert(key, value);
+        self
+    }
+
+    /// Adds a map of headers to send with each request
+    ///
+    /// # Arguments
+    ///
+    /// * `headers` - The headers to add
+    pub fn headers(mut self, headers: HeaderMap) -> Self {
+        self.headers.extend(headers);
+        self
+    }
+
+    /// Sets how requests that are rate limited or fail should be retried
+    ///
+    /// # Arguments
+    ///
+    /// * `retry` - The retry policy to use
+    pub fn retry(mut self, retry: RetryPolicy) -> Self {
+        self.retry = retry;
+        self
+    }
+
+    /// Sets a rate limiter that every request from this client must pass through
+    ///
+    /// # Arguments
+    ///
+    /// * `limiter` - The rate limiter to use
+    pub fn rate_limiter(mut self, limiter: RateLimiter) -> Self {
+        self.limiter = Some(limiter);
+        self
+    }
+
+    /// Sets the language to request data in by default
+    ///
+    /// This is only sent to routes that accept a language and never overrides a
+    /// language set on a single request.
+    ///
+    /// # Arguments
+    ///
+    /// * `language` - The ISO 639-1 language code to use such as `en-US`
+    pub fn language<T: Into<String>>(mut self, language: T) -> Self {
+        self.language = Some(language.into());
+        self
+    }
+
+    /// Sets the region to request data for by default
+    ///
+    /// This is only sent to routes that accept a region and never overrides a
+    /// region set on a single request.
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The ISO 3166-1 region code to use such as `US`
+    pub fn region<T: Into<String>>(mut self, region: T) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Sets how data from tmdb should be cached
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The cache settings to use
+    pub fn cache(mut self, cache: CacheConfig) -> Self {
+        self.cache = cache;
+        self
+    }
+
+    /// Builds a client with a single shared connection pool
+    pub fn build(self) -> Result<Client, Error> {
+        // build the reqwest client that all of our handlers will share
+        let mut builder = reqwest::Client::builder()
+            .timeout(self.timeout)
+            .default_headers(self.headers);
+        // add our optional settings if they were set
+        if let Some(proxy) = self.proxy {
+            builder = builder.proxy(proxy);
+        }
+        if let Some(user_agent) = self.user_agent {
+            builder = builder.user_agent(user_agent);
+        }
+        let transport = Transport {
+            host: self.host,
+            client: builder.build()?,
+            auth: self.auth,
+            retry: self.retry,
+            limiter: self.limiter,
+            language: self.language,
+            region: self.region,
         };
-        Self::new(token)
+        // build our handlers
+        let movies = Movies::new(&transport);
+        let tv = Tv::new(&transport);
+        let people = People::new(&transport);
+        let collections = Collections::new(&transport);
+        let companies = Companies::new(&transport);
+        let networks = Networks::new(&transport);
+        let trending = Trending::new(&transport);
+        let providers = Providers::new(&transport);
+        let certifications = Certifications::new(&transport);
+        Ok(Client {
+            movies,
+            tv,
+            people,
+            collections,
+            companies,
+            networks,
+            trending,
+            providers,
+            certifications,
+            transport,
+            cache: self.cache,
+            configuration: Mutex::new(None),
+        })
     }
 }
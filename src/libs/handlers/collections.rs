@@ -0,0 +1,290 @@
+use futures::stream::BoxStream;
+
+use super::core::{Defaults, Transport};
+use super::Cursor;
+use crate::libs::models::{Collection, CollectionSummary, Images, Translations};
+use crate::{get, opt_param, Error};
+
+/// Collection search cursor
+#[derive(Clone)]
+pub struct CollectionSearch<'a> {
+    /// The url to use
+    url: String,
+    /// The handler being used to perform this search
+    handler: &'a Collections,
+    /// The current page of this search
+    pub page: u64,
+    /// The query in use
+    pub query: String,
+    /// The region to search for collections in
+    pub region: Option<String>,
+    /// The language to return results in
+    pub language: Option<String>,
+    /// Whether adult collections should be returned
+    pub adult: bool,
+}
+
+impl<'a> CollectionSearch<'a> {
+    /// Search for collections on the currently selected page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // search for a collection
+    /// let search = tmdb.collections.search("Star Wars")
+    ///   .exec()
+    ///   .await;
+    /// # assert!(search.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn exec(self) -> Result<Cursor<CollectionSummary>, Error> {
+        // load the requested page of results for this search
+        let mut cursor = self.cursor();
+        cursor.load().await?;
+        Ok(cursor)
+    }
+
+    /// Builds a cursor for this search without loading any results
+    ///
+    /// This is useful for setting stream limits on the cursor before streaming it.
+    pub fn cursor(mut self) -> Cursor<CollectionSummary> {
+        // cast adult to a string
+        let adult = self.adult.to_string();
+        // build the url query params
+        let mut params: Vec<(String, String)> = Vec::with_capacity(2);
+        params.push(("query".into(), self.query));
+        params.push(("include_adult".into(), adult));
+        // add any optional params if they exist
+        opt_param!(params, "region", self.region);
+        opt_param!(params, "language", self.language);
+        // build cursor for this search
+        Cursor::new(self.url, &self.handler.transport)
+            .defaults(Defaults::LanguageAndRegion)
+            .page(self.page)
+            .params(params)
+    }
+
+    /// Streams the collections found by this search across all pages starting at the current page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // stream the first 40 results for our search
+    /// let collections: Vec<_> = tmdb.collections.search("Star")
+    ///   .into_stream()
+    ///   .take(40)
+    ///   .collect()
+    ///   .await;
+    /// # assert!(collections.iter().all(|res| res.is_ok()))
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<'static, Result<CollectionSummary, Error>> {
+        self.cursor().into_stream()
+    }
+
+    /// Change the current page of our search
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page to query when this search is executed
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Sets the region to search for collections in
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The region to search in
+    pub fn region<T: Into<String>>(mut self, region: T) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Sets the language to return results in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language to return results in
+    pub fn language<T: Into<String>>(mut self, lang: T) -> Self {
+        self.language = Some(lang.into());
+        self
+    }
+
+    /// Allows adult collections to be returned by this search
+    pub fn adult(mut self) -> Self {
+        self.adult = true;
+        self
+    }
+}
+
+/// Handlers for collection focused routes
+#[derive(Clone)]
+pub struct Collections {
+    /// The shared connection to tmdb
+    transport: Transport,
+}
+
+impl Collections {
+    /// Create a new Collections handler
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    pub(crate) fn new(transport: &Transport) -> Self {
+        // build collections handler
+        Collections {
+            transport: transport.clone(),
+        }
+    }
+
+    /// Search for a collection
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query to use when searching
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // search for a collection
+    /// let search = tmdb.collections.search("Star Wars")
+    ///   .exec()
+    ///   .await;
+    /// # assert!(search.is_ok())
+    /// # }
+    /// ```
+    pub fn search<T: Into<String>>(&self, query: T) -> CollectionSearch<'_> {
+        CollectionSearch {
+            url: format!("{}/3/search/collection", &self.transport.host),
+            handler: self,
+            // start at page 1 because tmdb doesn't use 0 based indexes
+            page: 1,
+            query: query.into(),
+            region: None,
+            language: None,
+            adult: false,
+        }
+    }
+
+    /// Get details on a collection by id
+    ///
+    /// The movies in this collection are ordered by release date with unreleased
+    /// movies last.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the collection to retrieve details on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // print the movies in a franchise in order
+    /// let collection = tmdb.collections.details(10).await.unwrap();
+    /// for movie in &collection.parts {
+    ///   println!("{}", movie.title);
+    /// }
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn details(&self, id: i64) -> Result<Collection, Error> {
+        // build url to query
+        let url = format!("{}/3/collection/{}", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a Collection object from the response
+        let mut collection: Collection = get!(self, req)?;
+        // tmdb doesn't order parts so sort them with unreleased movies last
+        collection
+            .parts
+            .sort_by_key(|movie| (movie.release_date.is_none(), movie.release_date));
+        Ok(collection)
+    }
+
+    /// Get the posters and backdrops for a collection
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the collection to retrieve images for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the images for a collection
+    /// let images = tmdb.collections.images(10).await;
+    /// # assert!(images.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn images(&self, id: i64) -> Result<Images, Error> {
+        // build url to query
+        let url = format!("{}/3/collection/{}/images", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an Images object from the response
+        get!(self, req)
+    }
+
+    /// Get the translations of a collection
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the collection to retrieve translations for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the translations for a collection
+    /// let translations = tmdb.collections.translations(10).await;
+    /// # assert!(translations.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn translations(&self, id: i64) -> Result<Translations, Error> {
+        // build url to query
+        let url = format!("{}/3/collection/{}/translations", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a Translations object from the response
+        get!(self, req)
+    }
+}
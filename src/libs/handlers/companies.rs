@@ -0,0 +1,292 @@
+use futures::stream::BoxStream;
+
+use super::core::{Defaults, Transport};
+use super::{Cursor, Join, MovieDiscover, ShowDiscover};
+use crate::libs::models::{AlternativeNames, Company, CompanyDetails, Images};
+use crate::{get, Error};
+
+/// Company search cursor
+#[derive(Clone)]
+pub struct CompanySearch<'a> {
+    /// The url to use
+    url: String,
+    /// The handler being used to perform this search
+    handler: &'a Companies,
+    /// The current page of this search
+    pub page: u64,
+    /// The query in use
+    pub query: String,
+}
+
+impl<'a> CompanySearch<'a> {
+    /// Search for companies on the currently selected page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // search for a company
+    /// let search = tmdb.companies.search("Pixar")
+    ///   .exec()
+    ///   .await;
+    /// # assert!(search.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn exec(self) -> Result<Cursor<Company>, Error> {
+        // load the requested page of results for this search
+        let mut cursor = self.cursor();
+        cursor.load().await?;
+        Ok(cursor)
+    }
+
+    /// Builds a cursor for this search without loading any results
+    ///
+    /// This is useful for setting stream limits on the cursor before streaming it.
+    pub fn cursor(self) -> Cursor<Company> {
+        // build the url query params
+        let params = vec![("query".to_owned(), self.query)];
+        // build cursor for this search
+        Cursor::new(self.url, &self.handler.transport)
+            .defaults(Defaults::None)
+            .page(self.page)
+            .params(params)
+    }
+
+    /// Streams the companies found by this search across all pages starting at the current page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // stream the first 40 results for our search
+    /// let companies: Vec<_> = tmdb.companies.search("Studios")
+    ///   .into_stream()
+    ///   .take(40)
+    ///   .collect()
+    ///   .await;
+    /// # assert!(companies.iter().all(|res| res.is_ok()))
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<'static, Result<Company, Error>> {
+        self.cursor().into_stream()
+    }
+
+    /// Change the current page of our search
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page to query when this search is executed
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = page;
+        self
+    }
+}
+
+/// Handlers for company focused routes
+#[derive(Clone)]
+pub struct Companies {
+    /// The shared connection to tmdb
+    transport: Transport,
+}
+
+impl Companies {
+    /// Create a new Companies handler
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    pub(crate) fn new(transport: &Transport) -> Self {
+        // build companies handler
+        Companies {
+            transport: transport.clone(),
+        }
+    }
+
+    /// Search for a company
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query to use when searching
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // search for a company
+    /// let search = tmdb.companies.search("Pixar")
+    ///   .exec()
+    ///   .await;
+    /// # assert!(search.is_ok())
+    /// # }
+    /// ```
+    pub fn search<T: Into<String>>(&self, query: T) -> CompanySearch<'_> {
+        CompanySearch {
+            url: format!("{}/3/search/company", &self.transport.host),
+            handler: self,
+            // start at page 1 because tmdb doesn't use 0 based indexes
+            page: 1,
+            query: query.into(),
+        }
+    }
+
+    /// Get details on a company by id
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the company to retrieve details on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get details on a company
+    /// let company = tmdb.companies.details(3).await;
+    /// # assert!(company.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn details(&self, id: i64) -> Result<CompanyDetails, Error> {
+        // build url to query
+        let url = format!("{}/3/company/{}", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a CompanyDetails object from the response
+        get!(self, req)
+    }
+
+    /// Get the other names a company is known by
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the company to retrieve alternative names for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the other names for a company
+    /// let names = tmdb.companies.alternative_names(3).await;
+    /// # assert!(names.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn alternative_names(&self, id: i64) -> Result<AlternativeNames, Error> {
+        // build url to query
+        let url = format!(
+            "{}/3/company/{}/alternative_names",
+            &self.transport.host, id
+        );
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an AlternativeNames object from the response
+        get!(self, req)
+    }
+
+    /// Get the logos for a company
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the company to retrieve images for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the logos for a company
+    /// let images = tmdb.companies.images(3).await;
+    /// # assert!(images.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn images(&self, id: i64) -> Result<Images, Error> {
+        // build url to query
+        let url = format!("{}/3/company/{}/images", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an Images object from the response
+        get!(self, req)
+    }
+
+    /// Discover the movies a company produced
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the company to discover movies for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmdb_cli::{Client, MovieSort};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the most popular movies from a company
+    /// let movies = tmdb.companies.movies(3)
+    ///   .sort_by(MovieSort::PopularityDesc)
+    ///   .exec()
+    ///   .await;
+    /// # assert!(movies.is_ok())
+    /// # }
+    /// ```
+    pub fn movies(&self, id: i64) -> MovieDiscover<'_> {
+        MovieDiscover::new(&self.transport).with_companies(vec![id], Join::And)
+    }
+
+    /// Discover the TV shows a company produced
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the company to discover shows for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the shows from a company
+    /// let shows = tmdb.companies.shows(3).exec().await;
+    /// # assert!(shows.is_ok())
+    /// # }
+    /// ```
+    pub fn shows(&self, id: i64) -> ShowDiscover<'_> {
+        ShowDiscover::new(&self.transport).with_companies(vec![id], Join::And)
+    }
+}
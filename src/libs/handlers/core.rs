@@ -1,10 +1,211 @@
+use reqwest::StatusCode;
+use serde::de::DeserializeOwned;
+
+use super::auth::Auth;
+use super::limiter::RateLimiter;
+use super::retry::RetryPolicy;
+use crate::Error;
+
+/// The default params that a route accepts
+#[derive(Clone, Copy, Debug, PartialEq, Eq)]
+pub enum Defaults {
+    /// The route accepts neither a language nor a region
+    None,
+    /// The route accepts a language
+    Language,
+    /// The route accepts a language and a region
+    LanguageAndRegion,
+}
+
+/// The connection state shared by every handler and cursor built from a [`Client`]
+///
+/// [`Client`]: super::Client
+#[derive(Clone, Debug)]
+pub struct Transport {
+    /// The URL/ip to reach tmdb at
+    pub host: String,
+    /// The reqwest client whose connection pool is shared across handlers
+    pub client: reqwest::Client,
+    /// How to authenticate to tmdb
+    pub auth: Auth,
+    /// How failed requests should be retried
+    pub retry: RetryPolicy,
+    /// The rate limiter every request must pass through
+    pub limiter: Option<RateLimiter>,
+    /// The language to request data in by default
+    pub language: Option<String>,
+    /// The region to request data for by default
+    pub region: Option<String>,
+}
+
+impl Transport {
+    /// Starts an authenticated GET request without any default params
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The url to query
+    pub fn get(&self, url: &str) -> reqwest::RequestBuilder {
+        self.auth.apply(self.client.get(url))
+    }
+
+    /// Starts an authenticated GET request with our default language
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The url to query
+    pub fn get_localized(&self, url: &str) -> reqwest::RequestBuilder {
+        self.get_with(url, Vec::new(), Defaults::Language)
+    }
+
+    /// Starts an authenticated GET request with params and any defaults they don't set
+    ///
+    /// # Arguments
+    ///
+    /// * `url` - The url to query
+    /// * `params` - The query params set for this request
+    /// * `defaults` - The default params this route accepts
+    pub fn get_with(
+        &self,
+        url: &str,
+        mut params: Vec<(String, String)>,
+        defaults: Defaults,
+    ) -> reqwest::RequestBuilder {
+        self.add_defaults(&mut params, defaults);
+        self.get(url).query(&params)
+    }
+
+    /// Adds our default language and region to params that don't already set them
+    ///
+    /// # Arguments
+    ///
+    /// * `params` - The query params set for a request
+    /// * `defaults` - The default params the route accepts
+    pub fn add_defaults(&self, params: &mut Vec<(String, String)>, defaults: Defaults) {
+        let (language, region) = match defaults {
+            Defaults::None => (None, None),
+            Defaults::Language => (self.language.as_ref(), None),
+            Defaults::LanguageAndRegion => (self.language.as_ref(), self.region.as_ref()),
+        };
+        for (key, value) in [("language", language), ("region", region)].iter() {
+            // only add defaults this request hasn't set itself
+            if let Some(value) = value {
+                if !params.iter().any(|(name, _)| name == key) {
+                    params.push(((*key).to_owned(), (*value).clone()));
+                }
+            }
+        }
+    }
+
+    /// Sends a request, retrying it according to our retry policy
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The request to send
+    pub async fn send(&self, req: reqwest::RequestBuilder) -> Result<reqwest::Response, Error> {
+        self.send_limited(req, self.limiter.as_ref(), &[]).await
+    }
+
+    /// Sends a request that skips our rate limiter such as an image from the cdn
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The request to send
+    pub async fn send_unlimited(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, Error> {
+        self.send_limited(req, None, &[]).await
+    }
+
+    /// Sends a conditional request that skips our rate limiter
+    ///
+    /// Unlike [`Transport::send_unlimited`] a `304 Not Modified` is returned as-is
+    /// instead of as an error so callers can check for it on the response.
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The request to send
+    pub async fn send_conditional(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<reqwest::Response, Error> {
+        self.send_limited(req, None, &[StatusCode::NOT_MODIFIED])
+            .await
+    }
+
+    /// Sends a request through a rate limiter, retrying it according to our retry policy
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The request to send
+    /// * `limiter` - The rate limiter to wait on before each attempt
+    /// * `passthrough` - The non success statuses to return as-is instead of as errors
+    async fn send_limited(
+        &self,
+        req: reqwest::RequestBuilder,
+        limiter: Option<&RateLimiter>,
+        passthrough: &[StatusCode],
+    ) -> Result<reqwest::Response, Error> {
+        let req = req.build()?;
+        let mut attempt = 1;
+        loop {
+            // wait for our rate limiter to allow this request
+            if let Some(limiter) = limiter {
+                limiter.limit().await?;
+            }
+            // requests with streaming bodies can't be cloned so only send those once
+            let result = match req.try_clone() {
+                Some(clone) => self.execute(clone, passthrough).await,
+                None => return self.execute(req, passthrough).await,
+            };
+            match result {
+                Err(err) if self.retry.should_retry(attempt, &err) => {
+                    // wait before trying this request again
+                    tokio::time::delay_for(self.retry.delay(attempt, &err)).await;
+                    attempt += 1;
+                }
+                result => return result,
+            }
+        }
+    }
+
+    /// Sends a request once and turns any non success status into an [`Error`]
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The request to send
+    /// * `passthrough` - The non success statuses to return as-is instead of as errors
+    async fn execute(
+        &self,
+        req: reqwest::Request,
+        passthrough: &[StatusCode],
+    ) -> Result<reqwest::Response, Error> {
+        let resp = self.client.execute(req).await?;
+        // parse the tmdb error body if this request failed
+        if resp.status().is_success() || passthrough.contains(&resp.status()) {
+            Ok(resp)
+        } else {
+            Err(Error::from_response(resp).await)
+        }
+    }
+
+    /// Sends a request and deserializes its response
+    ///
+    /// # Arguments
+    ///
+    /// * `req` - The request to send
+    pub async fn fetch<T: DeserializeOwned>(
+        &self,
+        req: reqwest::RequestBuilder,
+    ) -> Result<T, Error> {
+        let body = self.send(req).await?.bytes().await?;
+        Error::deserialize(&body)
+    }
+}
+
 #[macro_export]
 macro_rules! get {
     ($handler:expr, $req:expr) => {
-        $handler
-            .client
-            .execute($req.build()?)
-            .await?
-            .error_for_status()
+        $handler.transport.fetch($req).await
     };
 }
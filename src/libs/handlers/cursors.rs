@@ -1,6 +1,12 @@
+use futures::stream::{self, BoxStream, StreamExt, TryStreamExt};
 use serde::{de::DeserializeOwned, Deserialize};
+use std::collections::VecDeque;
 
-use crate::{client, get};
+use super::core::{Defaults, Transport};
+use crate::{get, Error};
+
+/// The last page tmdb will return results for
+const MAX_PAGE: u64 = 500;
 
 /// A cursor page that we will use to hydrate our cursor
 #[derive(Deserialize)]
@@ -10,9 +16,9 @@ struct CursorPage<T> {
     /// The currently retreived results
     pub results: Vec<T>,
     /// The total number of pages that exist
-    pub total_pages: i64,
+    pub total_pages: u64,
     /// The total number of results that exist
-    pub total_results: i64,
+    pub total_results: u64,
 }
 
 /// A cursor for objects of some type
@@ -20,10 +26,8 @@ struct CursorPage<T> {
 pub struct Cursor<T: DeserializeOwned> {
     /// The url to use
     pub url: String,
-    /// A reqwest client object
-    pub client: reqwest::Client,
-    /// A token to use when authenticating
-    pub token: String,
+    /// The shared connection to tmdb
+    transport: Transport,
     /// The page the cursor is currently at
     pub page: u64,
     /// The query params to use when paging through results with this cursor
@@ -31,9 +35,31 @@ pub struct Cursor<T: DeserializeOwned> {
     /// The currently retreived results
     pub results: Vec<T>,
     /// The total number of pages that exist
-    pub total_pages: i64,
+    pub total_pages: u64,
     /// The total number of results that exist
-    pub total_results: i64,
+    pub total_results: u64,
+    /// The max number of items to stream before stopping
+    pub max_items: Option<u64>,
+    /// The max number of pages to stream before stopping
+    pub max_pages: Option<u64>,
+    /// The default params the route for this cursor accepts
+    defaults: Defaults,
+    /// Whether this cursor has loaded a page yet
+    hydrated: bool,
+}
+
+/// The state of a cursor that is being streamed
+struct StreamState<T: DeserializeOwned> {
+    /// The cursor to load pages with
+    cursor: Cursor<T>,
+    /// The items from the current page that have not been yielded yet
+    buffer: VecDeque<T>,
+    /// The number of items yielded so far
+    items: u64,
+    /// The number of pages loaded so far
+    pages: u64,
+    /// Whether this stream has hit an error or run out of results
+    done: bool,
 }
 
 impl<T: DeserializeOwned> Cursor<T> {
@@ -42,20 +68,21 @@ impl<T: DeserializeOwned> Cursor<T> {
     /// # Arguments
     ///
     /// * `url` - The url this cursor should query
-    /// * `token` - The token used for authentication when querying TMDB
-    pub(super) fn new(url: String, token: &str) -> Cursor<T> {
-        // build client
-        let client = client!();
+    /// * `transport` - The shared connection used when querying TMDB
+    pub(super) fn new(url: String, transport: &Transport) -> Cursor<T> {
         // create default review cursor
         Cursor {
             url,
-            client,
-            token: token.to_owned(),
+            transport: transport.clone(),
             page: 1,
             params: Vec::default(),
             results: Vec::default(),
             total_pages: 0,
             total_results: 0,
+            max_items: None,
+            max_pages: None,
+            defaults: Defaults::Language,
+            hydrated: false,
         }
     }
 
@@ -95,31 +122,381 @@ impl<T: DeserializeOwned> Cursor<T> {
         self
     }
 
+    /// Sets the default params the route for this cursor accepts
+    ///
+    /// Cursors add the default language to their requests unless told otherwise.
+    ///
+    /// # Arguments
+    ///
+    /// * `defaults` - The default params this route accepts
+    pub(super) fn defaults(mut self, defaults: Defaults) -> Self {
+        self.defaults = defaults;
+        self
+    }
+
+    /// Sets the max number of items to yield when streaming this cursor
+    ///
+    /// # Arguments
+    ///
+    /// * `max_items` - The max number of items to yield
+    pub fn max_items(mut self, max_items: u64) -> Self {
+        self.max_items = Some(max_items);
+        self
+    }
+
+    /// Sets the max number of pages to load when streaming this cursor
+    ///
+    /// # Arguments
+    ///
+    /// * `max_pages` - The max number of pages to load
+    pub fn max_pages(mut self, max_pages: u64) -> Self {
+        self.max_pages = Some(max_pages);
+        self
+    }
+
     /// Load the data for the current page and params set
     #[syncwrap::wrap]
-    pub async fn exec(mut self) -> Result<Self, reqwest::Error> {
-        // build a request using the our token and query
-        let req = self
-            .client
-            .get(&self.url)
-            .query(&[("api_key", &self.token)])
-            .query(&[("page", &self.page)])
-            .query(&self.params);
-        // send request and build objects from the response to update our cursor
-        let data = get!(self, req)?.json::<CursorPage<T>>().await?;
-        // update our cursor
-        self.results = data.results;
-        self.total_pages = data.total_pages;
-        self.total_results = data.total_results;
+    pub async fn exec(mut self) -> Result<Self, Error> {
+        self.load().await?;
         Ok(self)
     }
 
     /// Load the data for the next page overwritting all data currently loaded
+    ///
+    /// A cursor that has not loaded a page yet loads its current page instead so
+    /// that paging through a new cursor starts with the first page. This fails
+    /// with [`Error::PageOutOfRange`] if the last page is already loaded.
     #[syncwrap::wrap]
-    pub async fn next_page(mut self) -> Result<Self, reqwest::Error> {
-        // increment our current page
-        self.page += 1;
+    pub async fn next_page(mut self) -> Result<Self, Error> {
+        // make sure there is a next page to load
+        if self.is_last() {
+            return Err(self.out_of_range(self.page + 1));
+        }
+        // only move past pages we have actually loaded
+        if self.hydrated {
+            self.page += 1;
+        }
         // load the data for the newly set page
-        self.exec().await
+        self.load().await?;
+        Ok(self)
+    }
+
+    /// Load the data for the previous page overwritting all data currently loaded
+    ///
+    /// This fails with [`Error::PageOutOfRange`] if the cursor is on the first page.
+    #[syncwrap::wrap]
+    pub async fn prev_page(mut self) -> Result<Self, Error> {
+        // make sure there is a previous page to load
+        if self.page <= 1 {
+            return Err(self.out_of_range(0));
+        }
+        // decrement our current page
+        self.page -= 1;
+        // load the data for the newly set page
+        self.load().await?;
+        Ok(self)
+    }
+
+    /// Load the data for a specific page overwritting all data currently loaded
+    ///
+    /// This fails with [`Error::PageOutOfRange`] if the page is 0 or past the
+    /// last page of an already loaded cursor.
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page to load
+    #[syncwrap::wrap]
+    pub async fn goto_page(mut self, page: u64) -> Result<Self, Error> {
+        // make sure this page exists
+        if page == 0 || (self.hydrated && page > self.last_page()) {
+            return Err(self.out_of_range(page));
+        }
+        self.page = page;
+        // load the data for the newly set page
+        self.load().await?;
+        Ok(self)
+    }
+
+    /// Whether this cursor has loaded a page and there is another page after it
+    ///
+    /// Tmdb does not return results past page 500 even if more pages exist.
+    pub fn has_next(&self) -> bool {
+        self.hydrated && self.page < self.last_page()
+    }
+
+    /// Whether this cursor has loaded the last page that tmdb will return
+    pub fn is_last(&self) -> bool {
+        self.hydrated && self.page >= self.last_page()
+    }
+
+    /// Get the last page that tmdb will return results for
+    fn last_page(&self) -> u64 {
+        self.total_pages.min(MAX_PAGE)
+    }
+
+    /// Build an error for navigating to a page that does not exist
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page that was requested
+    fn out_of_range(&self, page: u64) -> Error {
+        Error::PageOutOfRange {
+            page,
+            total_pages: self.total_pages,
+        }
+    }
+
+    /// Fetch a set of pages concurrently returning their items in page order
+    ///
+    /// This does not change the data the cursor currently has loaded. Requests
+    /// still pass through the clients rate limiter.
+    ///
+    /// # Arguments
+    ///
+    /// * `pages` - The pages to fetch
+    /// * `concurrency` - The max number of pages to fetch at once
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the first 5 pages of popular movies 3 pages at a time
+    /// let popular = tmdb.movies.popular().fetch_pages(1..=5, 3).await;
+    /// # assert!(popular.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn fetch_pages<P: IntoIterator<Item = u64>>(
+        &self,
+        pages: P,
+        concurrency: usize,
+    ) -> Result<Vec<T>, Error> {
+        self.fetch_many(pages, concurrency).await
+    }
+
+    /// Fetch every item from the current page onwards with pages fetched concurrently
+    ///
+    /// The current page is loaded first to find the total number of pages and
+    /// then the remaining pages are fetched concurrently. Items are returned in
+    /// page order. Tmdb does not return results past page 500.
+    ///
+    /// # Arguments
+    ///
+    /// * `concurrency` - The max number of pages to fetch at once
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get every review for a movie
+    /// let reviews = tmdb.movies.reviews(157336).collect_all(4).await;
+    /// # assert!(reviews.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn collect_all(mut self, concurrency: usize) -> Result<Vec<T>, Error> {
+        // load the current page to find out how many pages there are
+        if !self.hydrated {
+            self.load().await?;
+        }
+        let rest = self
+            .fetch_many(self.page + 1..=self.last_page(), concurrency)
+            .await?;
+        // start with the results for the current page
+        let mut items = std::mem::take(&mut self.results);
+        items.extend(rest);
+        Ok(items)
+    }
+
+    /// Streams every item across all pages of this cursor
+    ///
+    /// Pages are loaded lazily starting at the current page until either the
+    /// last page, [`max_items`](Cursor::max_items), or
+    /// [`max_pages`](Cursor::max_pages) is reached. If this cursor has already
+    /// been executed then its loaded results are yielded first.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // stream up to 50 reviews for a movie
+    /// let mut reviews = tmdb.movies.reviews(157336).max_items(50).into_stream();
+    /// while let Some(review) = reviews.next().await {
+    ///   # assert!(review.is_ok());
+    ///   println!("{}", review.unwrap().author);
+    /// }
+    /// # }
+    /// ```
+    pub fn into_stream(mut self) -> BoxStream<'static, Result<T, Error>>
+    where
+        T: Send + Sync + 'static,
+    {
+        // start with any results we have already loaded
+        let (buffer, pages) = if self.hydrated {
+            (std::mem::take(&mut self.results).into(), 1)
+        } else {
+            (VecDeque::default(), 0)
+        };
+        let state = StreamState {
+            cursor: self,
+            buffer,
+            items: 0,
+            pages,
+            done: false,
+        };
+        stream::unfold(state, |mut state| async move {
+            loop {
+                // stop early if we have yielded as many items as we were asked to
+                if matches!(state.cursor.max_items, Some(max) if state.items >= max) {
+                    return None;
+                }
+                if let Some(item) = state.buffer.pop_front() {
+                    state.items += 1;
+                    return Some((Ok(item), state));
+                }
+                // stop if we errored, hit our page limit, or reached the last page
+                let cursor = &mut state.cursor;
+                if state.done
+                    || matches!(cursor.max_pages, Some(max) if state.pages >= max)
+                    || cursor.is_last()
+                {
+                    return None;
+                }
+                // move to the next page if we have already loaded the current one
+                if cursor.hydrated {
+                    cursor.page += 1;
+                }
+                match cursor.load().await {
+                    Ok(()) => {
+                        state.pages += 1;
+                        state.buffer = std::mem::take(&mut cursor.results).into();
+                        // an empty page means there is nothing left to stream
+                        state.done = state.buffer.is_empty();
+                    }
+                    Err(e) => {
+                        state.done = true;
+                        return Some((Err(e), state));
+                    }
+                }
+            }
+        })
+        .boxed()
+    }
+
+    /// Load the data for the current page and params set into this cursor
+    pub(super) async fn load(&mut self) -> Result<(), Error> {
+        let data = self.fetch_page(self.page).await?;
+        // update our cursor
+        self.page = data.page;
+        self.results = data.results;
+        self.total_pages = data.total_pages;
+        self.total_results = data.total_results;
+        self.hydrated = true;
+        Ok(())
+    }
+
+    /// Fetch a single page using this cursors params without updating this cursor
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page to fetch
+    async fn fetch_page(&self, page: u64) -> Result<CursorPage<T>, Error> {
+        // build a request using the our token and query
+        let mut params = self.params.clone();
+        params.push(("page".into(), page.to_string()));
+        let req = self.transport.get_with(&self.url, params, self.defaults);
+        // send request and build objects from the response
+        get!(self, req)
+    }
+
+    /// Fetch a set of pages with bounded concurrency returning their items in page order
+    ///
+    /// # Arguments
+    ///
+    /// * `pages` - The pages to fetch
+    /// * `concurrency` - The max number of pages to fetch at once
+    async fn fetch_many<P: IntoIterator<Item = u64>>(
+        &self,
+        pages: P,
+        concurrency: usize,
+    ) -> Result<Vec<T>, Error> {
+        // buffered keeps our pages in order even if they finish out of order
+        let pages: Vec<CursorPage<T>> = stream::iter(pages)
+            .map(|page| self.fetch_page(page))
+            .buffered(concurrency.max(1))
+            .try_collect()
+            .await?;
+        Ok(pages.into_iter().flat_map(|page| page.results).collect())
+    }
+}
+
+/// A blocking iterator over every item across all pages of a cursor
+#[cfg(feature = "sync")]
+pub struct CursorIter<T> {
+    /// The runtime to load pages on
+    runtime: tokio::runtime::Runtime,
+    /// The stream of items being iterated over
+    stream: BoxStream<'static, Result<T, Error>>,
+}
+
+#[cfg(feature = "sync")]
+impl<T> Iterator for CursorIter<T> {
+    type Item = Result<T, Error>;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let stream = &mut self.stream;
+        self.runtime.block_on(stream.next())
+    }
+}
+
+/// Iterate over every item across all pages of a cursor
+///
+/// Pages are loaded as they are needed and respect the cursors stream limits.
+///
+/// # Examples
+///
+/// ```
+/// use tmdb_cli::Client;
+///
+/// // build a client
+/// let tmdb = Client::from_env();
+/// // print the first 100 popular movies
+/// for movie in tmdb.movies.popular().max_items(100) {
+///   # assert!(movie.is_ok());
+///   println!("{}", movie.unwrap().title);
+/// }
+/// ```
+#[cfg(feature = "sync")]
+impl<T: DeserializeOwned + Send + Sync + 'static> IntoIterator for Cursor<T> {
+    type Item = Result<T, Error>;
+    type IntoIter = CursorIter<T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        // build a runtime to load our pages on
+        let runtime = tokio::runtime::Builder::new()
+            .basic_scheduler()
+            .enable_all()
+            .build()
+            .expect("failed to build runtime");
+        CursorIter {
+            runtime,
+            stream: self.into_stream(),
+        }
     }
 }
@@ -0,0 +1,803 @@
+use chrono::prelude::*;
+use futures::stream::BoxStream;
+
+use super::core::{Defaults, Transport};
+use super::Cursor;
+use crate::libs::models::{Movie, Show};
+use crate::Error;
+
+/// How to combine a list of IDs when filtering
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Join {
+    /// Results must match every ID
+    And,
+    /// Results must match at least one ID
+    Or,
+}
+
+impl Join {
+    /// Get the separator tmdb uses for this join
+    fn separator(self) -> &'static str {
+        match self {
+            Join::And => ",",
+            Join::Or => "|",
+        }
+    }
+}
+
+/// How to sort the movies found by a [`MovieDiscover`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MovieSort {
+    /// Least popular first
+    PopularityAsc,
+    /// Most popular first
+    PopularityDesc,
+    /// Oldest release date first
+    ReleaseDateAsc,
+    /// Newest release date first
+    ReleaseDateDesc,
+    /// Oldest primary release date first
+    PrimaryReleaseDateAsc,
+    /// Newest primary release date first
+    PrimaryReleaseDateDesc,
+    /// Lowest revenue first
+    RevenueAsc,
+    /// Highest revenue first
+    RevenueDesc,
+    /// Original title from A to Z
+    OriginalTitleAsc,
+    /// Original title from Z to A
+    OriginalTitleDesc,
+    /// Lowest average vote first
+    VoteAverageAsc,
+    /// Highest average vote first
+    VoteAverageDesc,
+    /// Fewest votes first
+    VoteCountAsc,
+    /// Most votes first
+    VoteCountDesc,
+}
+
+impl MovieSort {
+    /// Get the value tmdb expects for this sort
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MovieSort::PopularityAsc => "popularity.asc",
+            MovieSort::PopularityDesc => "popularity.desc",
+            MovieSort::ReleaseDateAsc => "release_date.asc",
+            MovieSort::ReleaseDateDesc => "release_date.desc",
+            MovieSort::PrimaryReleaseDateAsc => "primary_release_date.asc",
+            MovieSort::PrimaryReleaseDateDesc => "primary_release_date.desc",
+            MovieSort::RevenueAsc => "revenue.asc",
+            MovieSort::RevenueDesc => "revenue.desc",
+            MovieSort::OriginalTitleAsc => "original_title.asc",
+            MovieSort::OriginalTitleDesc => "original_title.desc",
+            MovieSort::VoteAverageAsc => "vote_average.asc",
+            MovieSort::VoteAverageDesc => "vote_average.desc",
+            MovieSort::VoteCountAsc => "vote_count.asc",
+            MovieSort::VoteCountDesc => "vote_count.desc",
+        }
+    }
+}
+
+/// How to sort the shows found by a [`ShowDiscover`]
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ShowSort {
+    /// Least popular first
+    PopularityAsc,
+    /// Most popular first
+    PopularityDesc,
+    /// Oldest first air date first
+    FirstAirDateAsc,
+    /// Newest first air date first
+    FirstAirDateDesc,
+    /// Lowest average vote first
+    VoteAverageAsc,
+    /// Highest average vote first
+    VoteAverageDesc,
+    /// Fewest votes first
+    VoteCountAsc,
+    /// Most votes first
+    VoteCountDesc,
+}
+
+impl ShowSort {
+    /// Get the value tmdb expects for this sort
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ShowSort::PopularityAsc => "popularity.asc",
+            ShowSort::PopularityDesc => "popularity.desc",
+            ShowSort::FirstAirDateAsc => "first_air_date.asc",
+            ShowSort::FirstAirDateDesc => "first_air_date.desc",
+            ShowSort::VoteAverageAsc => "vote_average.asc",
+            ShowSort::VoteAverageDesc => "vote_average.desc",
+            ShowSort::VoteCountAsc => "vote_count.asc",
+            ShowSort::VoteCountDesc => "vote_count.desc",
+        }
+    }
+}
+
+/// Joins a list of IDs into a single param value
+///
+/// # Arguments
+///
+/// * `ids` - The IDs to join
+/// * `join` - How these IDs should be combined
+fn join_ids<I: IntoIterator<Item = i64>>(ids: I, join: Join) -> String {
+    ids.into_iter()
+        .map(|id| id.to_string())
+        .collect::<Vec<_>>()
+        .join(join.separator())
+}
+
+/// Sets a param replacing any previous value for it
+///
+/// # Arguments
+///
+/// * `params` - The params to update
+/// * `key` - The param to set
+/// * `value` - The value to set
+fn set_param(params: &mut Vec<(String, String)>, key: &str, value: String) {
+    params.retain(|(name, _)| name != key);
+    params.push((key.to_owned(), value));
+}
+
+/// Movie discover cursor
+#[derive(Clone)]
+pub struct MovieDiscover<'a> {
+    /// The url to use
+    url: String,
+    /// The shared connection to tmdb
+    transport: &'a Transport,
+    /// The current page of this discover
+    pub page: u64,
+    /// The filters to discover movies with
+    pub params: Vec<(String, String)>,
+}
+
+impl<'a> MovieDiscover<'a> {
+    /// Create a new movie discover with no filters
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    pub(super) fn new(transport: &'a Transport) -> Self {
+        MovieDiscover {
+            url: format!("{}/3/discover/movie", &transport.host),
+            transport,
+            // start at page 1 because tmdb doesn't use 0 based indexes
+            page: 1,
+            params: Vec::new(),
+        }
+    }
+
+    /// Discover movies on the currently selected page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmdb_cli::{Client, Join, MovieSort};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // find the most popular action or adventure movies over 2 hours long
+    /// let discover = tmdb.movies.discover()
+    ///   .sort_by(MovieSort::PopularityDesc)
+    ///   .genres(vec![28, 12], Join::Or)
+    ///   .runtime_gte(120)
+    ///   .exec()
+    ///   .await;
+    /// # assert!(discover.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn exec(self) -> Result<Cursor<Movie>, Error> {
+        // load the requested page of results for this discover
+        let mut cursor = self.cursor();
+        cursor.load().await?;
+        Ok(cursor)
+    }
+
+    /// Builds a cursor for this discover without loading any results
+    ///
+    /// This is useful for setting stream limits on the cursor before streaming it.
+    pub fn cursor(self) -> Cursor<Movie> {
+        Cursor::new(self.url, self.transport)
+            .defaults(Defaults::LanguageAndRegion)
+            .page(self.page)
+            .params(self.params)
+    }
+
+    /// Streams the movies found by this discover across all pages starting at the current page
+    pub fn into_stream(self) -> BoxStream<'static, Result<Movie, Error>> {
+        self.cursor().into_stream()
+    }
+
+    /// Sets a raw param for any filters without a typed setter
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The param to set
+    /// * `value` - The value to set
+    pub fn param<T: Into<String>>(mut self, key: &str, value: T) -> Self {
+        set_param(&mut self.params, key, value.into());
+        self
+    }
+
+    /// Change the current page of our discover
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page to query when this discover is executed
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Sets how to sort the movies that are found
+    ///
+    /// # Arguments
+    ///
+    /// * `sort` - The order to sort movies in
+    pub fn sort_by(self, sort: MovieSort) -> Self {
+        self.param("sort_by", sort.as_str())
+    }
+
+    /// Sets the genres movies must have
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the genres to filter on
+    /// * `join` - Whether movies must have all or any of these genres
+    pub fn genres<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_genres", join_ids(ids, join))
+    }
+
+    /// Sets the genres movies must not have
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the genres to exclude
+    /// * `join` - Whether movies with all or any of these genres are excluded
+    pub fn without_genres<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("without_genres", join_ids(ids, join))
+    }
+
+    /// Sets the earliest date movies can have been released on
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The earliest release date to allow
+    pub fn release_date_gte(self, date: NaiveDate) -> Self {
+        self.param("release_date.gte", date.to_string())
+    }
+
+    /// Sets the latest date movies can have been released on
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The latest release date to allow
+    pub fn release_date_lte(self, date: NaiveDate) -> Self {
+        self.param("release_date.lte", date.to_string())
+    }
+
+    /// Sets the earliest date movies can have been first released on
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The earliest primary release date to allow
+    pub fn primary_release_date_gte(self, date: NaiveDate) -> Self {
+        self.param("primary_release_date.gte", date.to_string())
+    }
+
+    /// Sets the latest date movies can have been first released on
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The latest primary release date to allow
+    pub fn primary_release_date_lte(self, date: NaiveDate) -> Self {
+        self.param("primary_release_date.lte", date.to_string())
+    }
+
+    /// Sets the year movies must have first been released in
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The primary release year to filter on
+    pub fn primary_release_year(self, year: u64) -> Self {
+        self.param("primary_release_year", year.to_string())
+    }
+
+    /// Sets the lowest average vote movies can have
+    ///
+    /// # Arguments
+    ///
+    /// * `vote` - The lowest average vote to allow
+    pub fn vote_average_gte(self, vote: f64) -> Self {
+        self.param("vote_average.gte", vote.to_string())
+    }
+
+    /// Sets the highest average vote movies can have
+    ///
+    /// # Arguments
+    ///
+    /// * `vote` - The highest average vote to allow
+    pub fn vote_average_lte(self, vote: f64) -> Self {
+        self.param("vote_average.lte", vote.to_string())
+    }
+
+    /// Sets the fewest votes movies can have
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The fewest votes to allow
+    pub fn vote_count_gte(self, count: u64) -> Self {
+        self.param("vote_count.gte", count.to_string())
+    }
+
+    /// Sets the most votes movies can have
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The most votes to allow
+    pub fn vote_count_lte(self, count: u64) -> Self {
+        self.param("vote_count.lte", count.to_string())
+    }
+
+    /// Sets the shortest runtime in minutes movies can have
+    ///
+    /// # Arguments
+    ///
+    /// * `minutes` - The shortest runtime to allow
+    pub fn runtime_gte(self, minutes: u64) -> Self {
+        self.param("with_runtime.gte", minutes.to_string())
+    }
+
+    /// Sets the longest runtime in minutes movies can have
+    ///
+    /// # Arguments
+    ///
+    /// * `minutes` - The longest runtime to allow
+    pub fn runtime_lte(self, minutes: u64) -> Self {
+        self.param("with_runtime.lte", minutes.to_string())
+    }
+
+    /// Sets the people that must be in the cast of movies
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the people to filter on
+    /// * `join` - Whether movies must star all or any of these people
+    pub fn with_cast<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_cast", join_ids(ids, join))
+    }
+
+    /// Sets the people that must be in the crew of movies
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the people to filter on
+    /// * `join` - Whether movies must have all or any of these people in their crew
+    pub fn with_crew<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_crew", join_ids(ids, join))
+    }
+
+    /// Sets the companies that must have produced movies
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the companies to filter on
+    /// * `join` - Whether movies must be from all or any of these companies
+    pub fn with_companies<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_companies", join_ids(ids, join))
+    }
+
+    /// Sets the keywords movies must have
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the keywords to filter on
+    /// * `join` - Whether movies must have all or any of these keywords
+    pub fn with_keywords<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_keywords", join_ids(ids, join))
+    }
+
+    /// Sets the keywords movies must not have
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the keywords to exclude
+    /// * `join` - Whether movies with all or any of these keywords are excluded
+    pub fn without_keywords<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("without_keywords", join_ids(ids, join))
+    }
+
+    /// Sets the watch providers movies must be available on
+    ///
+    /// This requires [`MovieDiscover::watch_region`] to also be set.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the watch providers to filter on
+    /// * `join` - Whether movies must be on all or any of these providers
+    pub fn with_watch_providers<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_watch_providers", join_ids(ids, join))
+    }
+
+    /// Sets the region to check watch providers in
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The ISO 3166-1 region code to check such as `US`
+    pub fn watch_region<T: Into<String>>(self, region: T) -> Self {
+        self.param("watch_region", region)
+    }
+
+    /// Sets the region to filter release dates on
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The ISO 3166-1 region code to use such as `US`
+    pub fn region<T: Into<String>>(self, region: T) -> Self {
+        self.param("region", region)
+    }
+
+    /// Sets the certification movies must have
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - The country the certification is from such as `US`
+    /// * `certification` - The certification to filter on such as `PG-13`
+    pub fn certification<T: Into<String>>(self, country: T, certification: T) -> Self {
+        self.param("certification_country", country)
+            .param("certification", certification)
+    }
+
+    /// Sets the highest certification movies can have
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - The country the certification is from such as `US`
+    /// * `certification` - The highest certification to allow such as `PG-13`
+    pub fn certification_lte<T: Into<String>>(self, country: T, certification: T) -> Self {
+        self.param("certification_country", country)
+            .param("certification.lte", certification)
+    }
+
+    /// Sets the original language movies must be in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The ISO 639-1 language code to filter on such as `en`
+    pub fn original_language<T: Into<String>>(self, lang: T) -> Self {
+        self.param("with_original_language", lang)
+    }
+
+    /// Sets the language to return results in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language to return results in
+    pub fn language<T: Into<String>>(self, lang: T) -> Self {
+        self.param("language", lang)
+    }
+
+    /// Allows adult movies to be returned by this discover
+    pub fn adult(self) -> Self {
+        self.param("include_adult", "true")
+    }
+}
+
+/// Show discover cursor
+#[derive(Clone)]
+pub struct ShowDiscover<'a> {
+    /// The url to use
+    url: String,
+    /// The shared connection to tmdb
+    transport: &'a Transport,
+    /// The current page of this discover
+    pub page: u64,
+    /// The filters to discover shows with
+    pub params: Vec<(String, String)>,
+}
+
+impl<'a> ShowDiscover<'a> {
+    /// Create a new show discover with no filters
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    pub(super) fn new(transport: &'a Transport) -> Self {
+        ShowDiscover {
+            url: format!("{}/3/discover/tv", &transport.host),
+            transport,
+            // start at page 1 because tmdb doesn't use 0 based indexes
+            page: 1,
+            params: Vec::new(),
+        }
+    }
+
+    /// Discover shows on the currently selected page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmdb_cli::{Client, Join, ShowSort};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // find the highest rated dramas with at least 100 votes
+    /// let discover = tmdb.tv.discover()
+    ///   .sort_by(ShowSort::VoteAverageDesc)
+    ///   .genres(vec![18], Join::And)
+    ///   .vote_count_gte(100)
+    ///   .exec()
+    ///   .await;
+    /// # assert!(discover.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn exec(self) -> Result<Cursor<Show>, Error> {
+        // load the requested page of results for this discover
+        let mut cursor = self.cursor();
+        cursor.load().await?;
+        Ok(cursor)
+    }
+
+    /// Builds a cursor for this discover without loading any results
+    ///
+    /// This is useful for setting stream limits on the cursor before streaming it.
+    pub fn cursor(self) -> Cursor<Show> {
+        Cursor::new(self.url, self.transport)
+            .page(self.page)
+            .params(self.params)
+    }
+
+    /// Streams the shows found by this discover across all pages starting at the current page
+    pub fn into_stream(self) -> BoxStream<'static, Result<Show, Error>> {
+        self.cursor().into_stream()
+    }
+
+    /// Sets a raw param for any filters without a typed setter
+    ///
+    /// # Arguments
+    ///
+    /// * `key` - The param to set
+    /// * `value` - The value to set
+    pub fn param<T: Into<String>>(mut self, key: &str, value: T) -> Self {
+        set_param(&mut self.params, key, value.into());
+        self
+    }
+
+    /// Change the current page of our discover
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page to query when this discover is executed
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Sets how to sort the shows that are found
+    ///
+    /// # Arguments
+    ///
+    /// * `sort` - The order to sort shows in
+    pub fn sort_by(self, sort: ShowSort) -> Self {
+        self.param("sort_by", sort.as_str())
+    }
+
+    /// Sets the genres shows must have
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the genres to filter on
+    /// * `join` - Whether shows must have all or any of these genres
+    pub fn genres<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_genres", join_ids(ids, join))
+    }
+
+    /// Sets the genres shows must not have
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the genres to exclude
+    /// * `join` - Whether shows with all or any of these genres are excluded
+    pub fn without_genres<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("without_genres", join_ids(ids, join))
+    }
+
+    /// Sets the earliest date shows can have first aired on
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The earliest first air date to allow
+    pub fn first_air_date_gte(self, date: NaiveDate) -> Self {
+        self.param("first_air_date.gte", date.to_string())
+    }
+
+    /// Sets the latest date shows can have first aired on
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The latest first air date to allow
+    pub fn first_air_date_lte(self, date: NaiveDate) -> Self {
+        self.param("first_air_date.lte", date.to_string())
+    }
+
+    /// Sets the earliest date shows must have had an episode air on
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The earliest air date to allow
+    pub fn air_date_gte(self, date: NaiveDate) -> Self {
+        self.param("air_date.gte", date.to_string())
+    }
+
+    /// Sets the latest date shows must have had an episode air on
+    ///
+    /// # Arguments
+    ///
+    /// * `date` - The latest air date to allow
+    pub fn air_date_lte(self, date: NaiveDate) -> Self {
+        self.param("air_date.lte", date.to_string())
+    }
+
+    /// Sets the year shows must have first aired in
+    ///
+    /// # Arguments
+    ///
+    /// * `year` - The first air date year to filter on
+    pub fn first_air_date_year(self, year: u64) -> Self {
+        self.param("first_air_date_year", year.to_string())
+    }
+
+    /// Sets the lowest average vote shows can have
+    ///
+    /// # Arguments
+    ///
+    /// * `vote` - The lowest average vote to allow
+    pub fn vote_average_gte(self, vote: f64) -> Self {
+        self.param("vote_average.gte", vote.to_string())
+    }
+
+    /// Sets the highest average vote shows can have
+    ///
+    /// # Arguments
+    ///
+    /// * `vote` - The highest average vote to allow
+    pub fn vote_average_lte(self, vote: f64) -> Self {
+        self.param("vote_average.lte", vote.to_string())
+    }
+
+    /// Sets the fewest votes shows can have
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The fewest votes to allow
+    pub fn vote_count_gte(self, count: u64) -> Self {
+        self.param("vote_count.gte", count.to_string())
+    }
+
+    /// Sets the most votes shows can have
+    ///
+    /// # Arguments
+    ///
+    /// * `count` - The most votes to allow
+    pub fn vote_count_lte(self, count: u64) -> Self {
+        self.param("vote_count.lte", count.to_string())
+    }
+
+    /// Sets the shortest episode runtime in minutes shows can have
+    ///
+    /// # Arguments
+    ///
+    /// * `minutes` - The shortest runtime to allow
+    pub fn runtime_gte(self, minutes: u64) -> Self {
+        self.param("with_runtime.gte", minutes.to_string())
+    }
+
+    /// Sets the longest episode runtime in minutes shows can have
+    ///
+    /// # Arguments
+    ///
+    /// * `minutes` - The longest runtime to allow
+    pub fn runtime_lte(self, minutes: u64) -> Self {
+        self.param("with_runtime.lte", minutes.to_string())
+    }
+
+    /// Sets the companies that must have produced shows
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the companies to filter on
+    /// * `join` - Whether shows must be from all or any of these companies
+    pub fn with_companies<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_companies", join_ids(ids, join))
+    }
+
+    /// Sets the networks shows must have aired on
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the networks to filter on
+    /// * `join` - Whether shows must have aired on all or any of these networks
+    pub fn with_networks<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_networks", join_ids(ids, join))
+    }
+
+    /// Sets the keywords shows must have
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the keywords to filter on
+    /// * `join` - Whether shows must have all or any of these keywords
+    pub fn with_keywords<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_keywords", join_ids(ids, join))
+    }
+
+    /// Sets the keywords shows must not have
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the keywords to exclude
+    /// * `join` - Whether shows with all or any of these keywords are excluded
+    pub fn without_keywords<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("without_keywords", join_ids(ids, join))
+    }
+
+    /// Sets the watch providers shows must be available on
+    ///
+    /// This requires [`ShowDiscover::watch_region`] to also be set.
+    ///
+    /// # Arguments
+    ///
+    /// * `ids` - The IDs of the watch providers to filter on
+    /// * `join` - Whether shows must be on all or any of these providers
+    pub fn with_watch_providers<I: IntoIterator<Item = i64>>(self, ids: I, join: Join) -> Self {
+        self.param("with_watch_providers", join_ids(ids, join))
+    }
+
+    /// Sets the region to check watch providers in
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The ISO 3166-1 region code to check such as `US`
+    pub fn watch_region<T: Into<String>>(self, region: T) -> Self {
+        self.param("watch_region", region)
+    }
+
+    /// Sets the certification shows must have
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - The country the certification is from such as `US`
+    /// * `certification` - The certification to filter on such as `TV-MA`
+    pub fn certification<T: Into<String>>(self, country: T, certification: T) -> Self {
+        self.param("certification_country", country)
+            .param("certification", certification)
+    }
+
+    /// Sets the original language shows must be in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The ISO 639-1 language code to filter on such as `en`
+    pub fn original_language<T: Into<String>>(self, lang: T) -> Self {
+        self.param("with_original_language", lang)
+    }
+
+    /// Sets the language to return results in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language to return results in
+    pub fn language<T: Into<String>>(self, lang: T) -> Self {
+        self.param("language", lang)
+    }
+}
@@ -0,0 +1,295 @@
+use reqwest::header::{CONTENT_LENGTH, ETAG, IF_NONE_MATCH};
+use reqwest::StatusCode;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Mutex;
+use tokio::fs;
+use tokio::io::AsyncWriteExt;
+
+use super::core::Transport;
+use crate::libs::config::CacheConfig;
+use crate::libs::models::ImageSize;
+use crate::Error;
+
+/// The name of the file image etags are kept in within the cache directory
+const ETAG_INDEX: &str = "image-etags.json";
+
+/// An image to download as part of a bulk download
+///
+/// # Examples
+///
+/// ```
+/// use tmdb_cli::models::PosterSize;
+/// use tmdb_cli::ImageDownload;
+///
+/// let poster = ImageDownload::new("/poster.jpg", PosterSize::W500, "Interstellar/poster.jpg");
+/// ```
+#[derive(Debug, Clone)]
+pub struct ImageDownload {
+    /// The path to the image such as a `poster_path`
+    pub path: String,
+    /// The size of the image to download which is only set from a typed size
+    pub(super) size: &'static str,
+    /// Where to write this image to
+    pub dest: PathBuf,
+}
+
+impl ImageDownload {
+    /// Creates a new image download
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the image such as a `poster_path`
+    /// * `size` - The size of the image to download
+    /// * `dest` - Where to write this image to
+    pub fn new<T: Into<String>, S: ImageSize, P: Into<PathBuf>>(path: T, size: S, dest: P) -> Self {
+        ImageDownload {
+            path: path.into(),
+            size: size.as_str(),
+            dest: dest.into(),
+        }
+    }
+}
+
+/// An image that was downloaded or was already on disk
+#[derive(Debug, Clone)]
+pub struct Download {
+    /// Where this image is on disk
+    pub path: PathBuf,
+    /// Whether the copy already on disk was current so nothing was downloaded
+    pub skipped: bool,
+}
+
+/// The ETags of downloaded images kept in a single file in the cache directory
+///
+/// Keeping these out of the directories images are written to means media
+/// libraries never see any files besides the images themselves.
+pub(super) struct EtagIndex {
+    /// Where this index is saved if a cache directory is set
+    path: Option<PathBuf>,
+    /// The etag of each image keyed by its absolute path
+    etags: Mutex<HashMap<String, String>>,
+    /// Whether any etags changed since this index was loaded
+    changed: AtomicBool,
+}
+
+impl EtagIndex {
+    /// Loads the etag index from a cache directory
+    ///
+    /// Without a cache directory etags are not kept and images are only
+    /// skipped if their size has not changed.
+    ///
+    /// # Arguments
+    ///
+    /// * `cache` - The cache settings to find our index with
+    pub(super) async fn load(cache: &CacheConfig) -> Self {
+        let path = cache.dir.as_ref().map(|dir| dir.join(ETAG_INDEX));
+        let etags = match &path {
+            // a missing or corrupt index just means every image gets revalidated
+            Some(path) => fs::read(path)
+                .await
+                .ok()
+                .and_then(|data| serde_json::from_slice(&data).ok())
+                .unwrap_or_default(),
+            None => HashMap::new(),
+        };
+        EtagIndex {
+            path,
+            etags: Mutex::new(etags),
+            changed: AtomicBool::new(false),
+        }
+    }
+
+    /// Get the saved etag for an image
+    ///
+    /// # Arguments
+    ///
+    /// * `dest` - Where the image is on disk
+    fn get(&self, dest: &Path) -> Option<String> {
+        let etags = self.etags.lock().unwrap_or_else(|e| e.into_inner());
+        etags.get(&index_key(dest)).cloned()
+    }
+
+    /// Saves the etag for an image or forgets a stale one if tmdb didn't send one
+    ///
+    /// # Arguments
+    ///
+    /// * `dest` - Where the image is on disk
+    /// * `etag` - The etag to save
+    fn set(&self, dest: &Path, etag: Option<String>) {
+        // there's nowhere to persist etags without a cache directory
+        if self.path.is_none() {
+            return;
+        }
+        let mut etags = self.etags.lock().unwrap_or_else(|e| e.into_inner());
+        let key = index_key(dest);
+        let changed = match etag {
+            Some(etag) => etags.insert(key, etag.clone()) != Some(etag),
+            None => etags.remove(&key).is_some(),
+        };
+        if changed {
+            self.changed.store(true, Ordering::Relaxed);
+        }
+    }
+
+    /// Writes this index to the cache directory if any etags changed
+    pub(super) async fn save(&self) -> Result<(), Error> {
+        let path = match &self.path {
+            Some(path) if self.changed.load(Ordering::Relaxed) => path,
+            _ => return Ok(()),
+        };
+        let data = {
+            let etags = self.etags.lock().unwrap_or_else(|e| e.into_inner());
+            serde_json::to_vec(&*etags).expect("etags are always serializable")
+        };
+        if let Some(parent) = path.parent() {
+            fs::create_dir_all(parent).await?;
+        }
+        // swap the new index into place so a crash never leaves a partial one
+        let tmp = temp_path(path);
+        fs::write(&tmp, data).await?;
+        fs::rename(&tmp, path).await?;
+        self.changed.store(false, Ordering::Relaxed);
+        Ok(())
+    }
+}
+
+/// Downloads an image to disk unless the copy already there is current
+///
+/// The image is written to a temp file next to `dest` and then renamed into
+/// place so a failed download never replaces a good image. The ETag of each
+/// image is kept in `index` so later downloads can be skipped. Images without a
+/// saved ETag are checked with a HEAD request first and skipped
+/// if their size has not changed.
+///
+/// # Arguments
+///
+/// * `transport` - The shared connection to send requests with
+/// * `index` - The etags of images already on disk
+/// * `url` - The url of the image to download
+/// * `dest` - Where to write this image to
+pub(super) async fn download(
+    transport: &Transport,
+    index: &EtagIndex,
+    url: &str,
+    dest: PathBuf,
+) -> Result<Download, Error> {
+    // only trust a saved etag if the image it belongs to still exists
+    let existing = fs::metadata(&dest).await.ok().filter(|meta| meta.is_file());
+    let saved = match existing {
+        Some(_) => index.get(&dest),
+        None => None,
+    };
+    // images are served from a cdn so don't send our credentials along and since
+    // the cdn isn't covered by the api rate limit don't spend tokens on it either
+    let resp = match (&existing, saved) {
+        (_, Some(etag)) => {
+            let req = transport.client.get(url).header(IF_NONE_MATCH, etag);
+            let resp = transport.send_conditional(req).await?;
+            // the image on disk is still current
+            if resp.status() == StatusCode::NOT_MODIFIED {
+                return Ok(Download {
+                    path: dest,
+                    skipped: true,
+                });
+            }
+            resp
+        }
+        (Some(meta), None) => {
+            // without an etag only download images whose size has changed
+            let head = transport.send_unlimited(transport.client.head(url)).await?;
+            if content_length(&head) == Some(meta.len()) {
+                index.set(&dest, etag(&head));
+                return Ok(Download {
+                    path: dest,
+                    skipped: true,
+                });
+            }
+            transport.send_unlimited(transport.client.get(url)).await?
+        }
+        (None, None) => transport.send_unlimited(transport.client.get(url)).await?,
+    };
+    let etag = etag(&resp);
+    if let Some(parent) = dest.parent() {
+        fs::create_dir_all(parent).await?;
+    }
+    // write to a temp file first so partial downloads are never left in place
+    let tmp = temp_path(&dest);
+    if let Err(err) = write_body(resp, &tmp).await {
+        let _ = fs::remove_file(&tmp).await;
+        return Err(err);
+    }
+    fs::rename(&tmp, &dest).await?;
+    index.set(&dest, etag);
+    Ok(Download {
+        path: dest,
+        skipped: false,
+    })
+}
+
+/// Streams a response body into a file
+///
+/// # Arguments
+///
+/// * `resp` - The response whose body should be written
+/// * `path` - The file to write to
+async fn write_body(mut resp: reqwest::Response, path: &Path) -> Result<(), Error> {
+    let mut file = fs::File::create(path).await?;
+    while let Some(chunk) = resp.chunk().await? {
+        file.write_all(&chunk).await?;
+    }
+    file.flush().await?;
+    Ok(())
+}
+
+/// Get the etag of a response if it has one
+///
+/// # Arguments
+///
+/// * `resp` - The response to get an etag from
+fn etag(resp: &reqwest::Response) -> Option<String> {
+    resp.headers()
+        .get(ETAG)
+        .and_then(|etag| etag.to_str().ok())
+        .map(|etag| etag.to_owned())
+}
+
+/// Get the size of an image from the Content-Length header of a response
+///
+/// This reads the header directly since HEAD responses never have a body.
+///
+/// # Arguments
+///
+/// * `resp` - The response to get a size from
+fn content_length(resp: &reqwest::Response) -> Option<u64> {
+    resp.headers()
+        .get(CONTENT_LENGTH)
+        .and_then(|len| len.to_str().ok())
+        .and_then(|len| len.parse().ok())
+}
+
+/// Get the key for an image in our etag index
+///
+/// # Arguments
+///
+/// * `dest` - Where the image is on disk
+fn index_key(dest: &Path) -> String {
+    // key by absolute path so relative paths from other directories never collide
+    let path = std::env::current_dir()
+        .map(|cwd| cwd.join(dest))
+        .unwrap_or_else(|_| dest.to_path_buf());
+    path.to_string_lossy().into_owned()
+}
+
+/// Get a unique temp path next to an image to download it to
+///
+/// # Arguments
+///
+/// * `dest` - The path to the image
+fn temp_path(dest: &Path) -> PathBuf {
+    let mut name = std::ffi::OsString::from(".");
+    name.push(dest.file_name().unwrap_or_default());
+    name.push(format!(".{}.part", rand::random::<u32>()));
+    dest.with_file_name(name)
+}
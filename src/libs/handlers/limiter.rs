@@ -0,0 +1,110 @@
+use std::sync::{Arc, Mutex};
+use std::time::{Duration, Instant};
+
+use crate::Error;
+
+/// The current state of a token bucket
+#[derive(Debug)]
+struct Bucket {
+    /// The number of requests that can currently be sent
+    tokens: f64,
+    /// When tokens were last added to this bucket
+    updated: Instant,
+}
+
+/// A token bucket rate limiter shared by every handler and cursor of a client
+///
+/// Cloning a limiter shares its bucket so a clone can be kept around to check
+/// the limit from outside of the client.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use tmdb_cli::{Client, RateLimiter};
+///
+/// // allow bursts of 40 requests every 10 seconds
+/// let limiter = RateLimiter::new(40, Duration::from_secs(10));
+/// let tmdb = Client::builder("TMDB_TOKEN")
+///   .rate_limiter(limiter.clone())
+///   .build()
+///   .unwrap();
+/// // check if a request could be sent right now without waiting
+/// assert!(limiter.try_acquire().is_ok());
+/// ```
+#[derive(Clone, Debug)]
+pub struct RateLimiter {
+    /// The bucket of tokens shared across clones of this limiter
+    bucket: Arc<Mutex<Bucket>>,
+    /// The max number of tokens this bucket can hold
+    capacity: f64,
+    /// The number of tokens added to this bucket each second
+    rate: f64,
+    /// Whether requests should fail instead of waiting for a token
+    nonblocking: bool,
+}
+
+impl RateLimiter {
+    /// Creates a new rate limiter
+    ///
+    /// # Arguments
+    ///
+    /// * `requests` - The number of requests that can be sent within `per`
+    /// * `per` - The window of time requests are limited over
+    pub fn new(requests: u32, per: Duration) -> Self {
+        let capacity = f64::from(requests.max(1));
+        let bucket = Bucket {
+            tokens: capacity,
+            updated: Instant::now(),
+        };
+        RateLimiter {
+            bucket: Arc::new(Mutex::new(bucket)),
+            capacity,
+            rate: capacity / per.as_secs_f64().max(f64::EPSILON),
+            nonblocking: false,
+        }
+    }
+
+    /// Fail requests with [`Error::RateLimited`] instead of waiting for the limit
+    pub fn nonblocking(mut self) -> Self {
+        self.nonblocking = true;
+        self
+    }
+
+    /// Try to take a token without waiting
+    ///
+    /// On failure this returns how long until a token will be available.
+    pub fn try_acquire(&self) -> Result<(), Duration> {
+        let mut bucket = self.bucket.lock().unwrap_or_else(|e| e.into_inner());
+        // refill our bucket based on how long its been since we last checked
+        let now = Instant::now();
+        let elapsed = now.duration_since(bucket.updated).as_secs_f64();
+        bucket.tokens = (bucket.tokens + elapsed * self.rate).min(self.capacity);
+        bucket.updated = now;
+        if bucket.tokens >= 1.0 {
+            bucket.tokens -= 1.0;
+            Ok(())
+        } else {
+            Err(Duration::from_secs_f64((1.0 - bucket.tokens) / self.rate))
+        }
+    }
+
+    /// Wait until a token is available and take it
+    pub async fn acquire(&self) {
+        while let Err(wait) = self.try_acquire() {
+            tokio::time::delay_for(wait).await;
+        }
+    }
+
+    /// Take a token either waiting or failing based on this limiters mode
+    pub(crate) async fn limit(&self) -> Result<(), Error> {
+        if self.nonblocking {
+            self.try_acquire().map_err(|wait| Error::RateLimited {
+                retry_after: Some(wait),
+            })
+        } else {
+            self.acquire().await;
+            Ok(())
+        }
+    }
+}
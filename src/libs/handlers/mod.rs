@@ -1,9 +1,32 @@
+mod auth;
+mod certifications;
 pub mod client;
+mod collections;
+mod companies;
 mod core;
 pub mod cursors;
+mod discover;
+mod download;
 mod helpers;
+mod limiter;
 mod movies;
+mod networks;
+mod people;
+mod providers;
+mod retry;
+mod search;
+mod trending;
 mod tv;
 
-pub use client::Client;
+pub use auth::Auth;
+pub use client::{Client, ClientBuilder};
 pub use cursors::Cursor;
+#[cfg(feature = "sync")]
+pub use cursors::CursorIter;
+pub use discover::{Join, MovieDiscover, MovieSort, ShowDiscover, ShowSort};
+pub use download::{Download, ImageDownload};
+pub use limiter::RateLimiter;
+pub use retry::RetryPolicy;
+pub use providers::ProviderList;
+pub use search::MultiSearch;
+pub use trending::{MediaType, TimeWindow};
@@ -1,6 +1,13 @@
-use super::Cursor;
-use crate::libs::models::{Credits, Movie, MovieDetails, Review};
-use crate::{client, get, opt_param};
+use futures::stream::BoxStream;
+use std::collections::HashMap;
+
+use super::core::{Defaults, Transport};
+use super::{Cursor, MovieDiscover};
+use crate::libs::models::{
+    CountryCode, Credits, ExternalIds, Images, Movie, MovieDetails, MovieDetailsBundle,
+    RegionProviders, ReleaseDates, Review, Video, Videos, WatchProviders,
+};
+use crate::{get, opt_param, Error};
 
 /// Movie search cursor
 #[derive(Clone)]
@@ -46,7 +53,17 @@ impl<'a> MovieSearch<'a> {
     /// # }
     /// ```
     #[syncwrap::wrap]
-    pub async fn exec(mut self) -> Result<Cursor<Movie>, reqwest::Error> {
+    pub async fn exec(self) -> Result<Cursor<Movie>, Error> {
+        // load the requested page of results for this search
+        let mut cursor = self.cursor();
+        cursor.load().await?;
+        Ok(cursor)
+    }
+
+    /// Builds a cursor for this search without loading any results
+    ///
+    /// This is useful for setting stream limits on the cursor before streaming it.
+    pub fn cursor(mut self) -> Cursor<Movie> {
         // cast page to a string
         let adult = self.adult.to_string();
         // build the url query params
@@ -59,11 +76,36 @@ impl<'a> MovieSearch<'a> {
         opt_param!(params, "primary_year", self.primary_year);
         opt_param!(params, "language", self.language);
         // build cursor for this search
-        Cursor::new(self.url, &self.handler.token)
+        Cursor::new(self.url, &self.handler.transport)
+            .defaults(Defaults::LanguageAndRegion)
             .page(self.page)
             .params(params)
-            .next_page()
-            .await
+    }
+
+    /// Streams the movies found by this search across all pages starting at the current page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // stream the first 40 results for our search
+    /// let movies: Vec<_> = tmdb.movies.search("13 Hours")
+    ///   .year(2016)
+    ///   .into_stream()
+    ///   .take(40)
+    ///   .collect()
+    ///   .await;
+    /// # assert!(movies.iter().all(|res| res.is_ok()))
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<'static, Result<Movie, Error>> {
+        self.cursor().into_stream()
     }
 
     /// Change the current page of our search
@@ -123,15 +165,132 @@ impl<'a> MovieSearch<'a> {
     }
 }
 
+/// A request for details on a movie with extra sections appended
+///
+/// Each appended section is fetched in the same request using `append_to_response`.
+#[derive(Clone)]
+pub struct MovieDetailsWith<'a> {
+    /// The ID of the movie to get details on
+    id: i64,
+    /// The handler being used to perform this request
+    handler: &'a Movies,
+    /// The sections to append to this request
+    pub sections: Vec<&'static str>,
+    /// The language to get details in
+    pub language: Option<String>,
+    /// The extra languages to include appended videos in
+    pub video_languages: Vec<String>,
+}
+
+impl<'a> MovieDetailsWith<'a> {
+    /// Get the details and any appended sections for this movie
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get details, credits and videos in a single request
+    /// let bundle = tmdb.movies.details_with(157336)
+    ///   .credits()
+    ///   .videos()
+    ///   .exec()
+    ///   .await;
+    /// # assert!(bundle.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn exec(mut self) -> Result<MovieDetailsBundle, Error> {
+        // build url to query
+        let url = format!("{}/3/movie/{}", &self.handler.transport.host, self.id);
+        // build the url query params
+        let mut params: Vec<(String, String)> = Vec::with_capacity(2);
+        if !self.sections.is_empty() {
+            params.push(("append_to_response".into(), self.sections.join(",")));
+        }
+        opt_param!(params, "language", self.language);
+        if !self.video_languages.is_empty() {
+            params.push((
+                "include_video_language".into(),
+                self.video_languages.join(","),
+            ));
+        }
+        // build a request using the our token and query
+        let req = self
+            .handler
+            .transport
+            .get_with(&url, params, Defaults::Language);
+        // send request and build a MovieDetailsBundle object from the response
+        get!(self.handler, req)
+    }
+
+    /// Adds a section to append if it hasn't been added already
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The section to append
+    fn append(mut self, section: &'static str) -> Self {
+        if !self.sections.contains(&section) {
+            self.sections.push(section);
+        }
+        self
+    }
+
+    /// Appends the cast and crew to this request
+    pub fn credits(self) -> Self {
+        self.append("credits")
+    }
+
+    /// Appends the videos to this request
+    pub fn videos(self) -> Self {
+        self.append("videos")
+    }
+
+    /// Appends the images to this request
+    pub fn images(self) -> Self {
+        self.append("images")
+    }
+
+    /// Appends the IDs in other databases to this request
+    pub fn external_ids(self) -> Self {
+        self.append("external_ids")
+    }
+
+    /// Appends the release dates and certifications to this request
+    pub fn release_dates(self) -> Self {
+        self.append("release_dates")
+    }
+
+    /// Sets the language to get details in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language to get details in
+    pub fn language<T: Into<String>>(mut self, lang: T) -> Self {
+        self.language = Some(lang.into());
+        self
+    }
+
+    /// Sets the extra languages to include appended videos in
+    ///
+    /// # Arguments
+    ///
+    /// * `languages` - The languages to include such as `en` or `null` for videos without a language
+    pub fn video_languages(mut self, languages: &[&str]) -> Self {
+        self.video_languages = languages.iter().map(|lang| lang.to_string()).collect();
+        self
+    }
+}
+
 /// Handlers for Movie focused routes
 #[derive(Clone)]
 pub struct Movies {
-    /// The URL/ip to reach tmdb at
-    host: String,
-    /// A reqwest client object
-    pub client: reqwest::Client,
-    /// A token to use when authenticating
-    pub token: String,
+    /// The shared connection to tmdb
+    transport: Transport,
 }
 
 impl Movies {
@@ -139,16 +298,11 @@ impl Movies {
     ///
     /// # Arguments
     ///
-    /// * `host` - The host/url tmdb is at
-    /// * `token` - The token to use when authenticating
-    pub fn new(host: &str, token: &str) -> Self {
-        // build client
-        let client = client!();
+    /// * `transport` - The shared connection to tmdb
+    pub(crate) fn new(transport: &Transport) -> Self {
         // build movies handler
         Movies {
-            host: host.to_owned(),
-            client,
-            token: token.to_owned(),
+            transport: transport.clone(),
         }
     }
 
@@ -175,9 +329,9 @@ impl Movies {
     /// # assert!(search.is_ok())
     /// # }
     /// ```
-    pub fn search<T: Into<String>>(&self, query: T) -> MovieSearch {
+    pub fn search<T: Into<String>>(&self, query: T) -> MovieSearch<'_> {
         MovieSearch {
-            url: format!("{}/3/search/movie", &self.host),
+            url: format!("{}/3/search/movie", &self.transport.host),
             handler: self,
             // start at page 1 because tmdb doesn't use 0 based indexes
             page: 1,
@@ -190,6 +344,29 @@ impl Movies {
         }
     }
 
+    /// Discover movies by filtering the whole catalog
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmdb_cli::{Client, Join};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // discover movies in a genre
+    /// let discover = tmdb.movies.discover()
+    ///   .genres(vec![28], Join::And)
+    ///   .exec()
+    ///   .await;
+    /// # assert!(discover.is_ok())
+    /// # }
+    /// ```
+    pub fn discover(&self) -> MovieDiscover<'_> {
+        MovieDiscover::new(&self.transport)
+    }
+
     /// Get details on a movie by id
     ///
     /// # Arguments
@@ -211,13 +388,48 @@ impl Movies {
     /// # }
     /// ```
     #[syncwrap::wrap]
-    pub async fn details(&self, id: i64) -> Result<MovieDetails, reqwest::Error> {
+    pub async fn details(&self, id: i64) -> Result<MovieDetails, Error> {
         // build url to query
-        let url = format!("{}/3/movie/{}", &self.host, id);
+        let url = format!("{}/3/movie/{}", &self.transport.host, id);
         // build a request using the our token and query
-        let req = self.client.get(&url).query(&[("api_key", &self.token)]);
+        let req = self.transport.get_localized(&url);
         // send request and build a MovieDetails object from the response
-        get!(self, req)?.json::<MovieDetails>().await
+        get!(self, req)
+    }
+
+    /// Get details on a movie along with extra sections in a single request
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the movie to retrieve details on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get details, credits, images and external ids in a single request
+    /// let bundle = tmdb.movies.details_with(157336)
+    ///   .credits()
+    ///   .images()
+    ///   .external_ids()
+    ///   .exec()
+    ///   .await;
+    /// # assert!(bundle.is_ok())
+    /// # }
+    /// ```
+    pub fn details_with(&self, id: i64) -> MovieDetailsWith<'_> {
+        MovieDetailsWith {
+            id,
+            handler: self,
+            sections: Vec::new(),
+            language: None,
+            video_languages: Vec::new(),
+        }
     }
 
     /// Get the credis for a movie by id
@@ -241,13 +453,221 @@ impl Movies {
     /// # }
     /// ```
     #[syncwrap::wrap]
-    pub async fn credits(&self, id: i64) -> Result<Credits, reqwest::Error> {
+    pub async fn credits(&self, id: i64) -> Result<Credits, Error> {
         // build url to query
-        let url = format!("{}/3/movie/{}/credits", &self.host, id);
+        let url = format!("{}/3/movie/{}/credits", &self.transport.host, id);
         // build a request using the our token and query
-        let req = self.client.get(&url).query(&[("api_key", &self.token)]);
+        let req = self.transport.get_localized(&url);
         // send request and build a Credits object from the response
-        get!(self, req)?.json::<Credits>().await
+        get!(self, req)
+    }
+
+    /// Get the IDs for a movie in other databases
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the movie to retrieve external IDs for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the imdb and wikidata ids for a movie
+    /// let ids = tmdb.movies.external_ids(157336).await;
+    /// # assert!(ids.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn external_ids(&self, id: i64) -> Result<ExternalIds, Error> {
+        // build url to query
+        let url = format!("{}/3/movie/{}/external_ids", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an ExternalIds object from the response
+        get!(self, req)
+    }
+
+    /// Get the posters, backdrops and logos for a movie
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the movie to retrieve images for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the images for a movie
+    /// let images = tmdb.movies.images(157336).await;
+    /// # assert!(images.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn images(&self, id: i64) -> Result<Images, Error> {
+        // build url to query
+        let url = format!("{}/3/movie/{}/images", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an Images object from the response
+        get!(self, req)
+    }
+
+    /// Get the releases and certifications of a movie in each country
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the movie to retrieve releases for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the releases for a movie
+    /// let release_dates = tmdb.movies.release_dates(157336).await;
+    /// # assert!(release_dates.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn release_dates(&self, id: i64) -> Result<ReleaseDates, Error> {
+        // build url to query
+        let url = format!("{}/3/movie/{}/release_dates", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a ReleaseDates object from the response
+        get!(self, req)
+    }
+
+    /// Get where a movie can be watched in each country
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the movie to find watch providers for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // print where a movie can be streamed in the US
+    /// let providers = tmdb.movies.watch_providers(157336).await.unwrap();
+    /// if let Some(us) = providers.get("US") {
+    ///   for provider in &us.flatrate {
+    ///     println!("{}", provider.provider_name);
+    ///   }
+    /// }
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn watch_providers(
+        &self,
+        id: i64,
+    ) -> Result<HashMap<CountryCode, RegionProviders>, Error> {
+        // build url to query
+        let url = format!("{}/3/movie/{}/watch/providers", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a WatchProviders object from the response
+        let providers: WatchProviders = get!(self, req)?;
+        Ok(providers.results)
+    }
+
+    /// Get the videos such as trailers for a movie
+    ///
+    /// Only videos in the language results are returned in are included. Use
+    /// [`Movies::videos_in_languages`] to get videos in other languages.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the movie to retrieve videos for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the videos for a movie
+    /// let videos = tmdb.movies.videos(157336).await;
+    /// # assert!(videos.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn videos(&self, id: i64) -> Result<Vec<Video>, Error> {
+        self.load_videos(id, &[]).await
+    }
+
+    /// Get the videos such as trailers for a movie in extra languages
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the movie to retrieve videos for
+    /// * `languages` - The extra languages to include videos in such as `en` or `null` for videos without a language
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    /// use tmdb_cli::models::Video;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the videos for a movie in english or without a language
+    /// let videos = tmdb.movies.videos_in_languages(157336, &["en", "null"]).await.unwrap();
+    /// let trailer = Video::best_trailer(&videos, &["en"]);
+    /// # assert!(trailer.is_some())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn videos_in_languages(
+        &self,
+        id: i64,
+        languages: &[&str],
+    ) -> Result<Vec<Video>, Error> {
+        self.load_videos(id, languages).await
+    }
+
+    /// Get the videos for a movie optionally including extra languages
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the movie to retrieve videos for
+    /// * `languages` - The extra languages to include videos in
+    async fn load_videos(&self, id: i64, languages: &[&str]) -> Result<Vec<Video>, Error> {
+        // build url to query
+        let url = format!("{}/3/movie/{}/videos", &self.transport.host, id);
+        // build a request using the our token and query
+        let mut params: Vec<(String, String)> = Vec::with_capacity(1);
+        if !languages.is_empty() {
+            params.push(("include_video_language".into(), languages.join(",")));
+        }
+        let req = self.transport.get_with(&url, params, Defaults::Language);
+        // send request and build a Videos object from the response
+        let videos: Videos = get!(self, req)?;
+        Ok(videos.results)
     }
 
     /// Builds a cursor for the reviews for a movie
@@ -272,9 +692,9 @@ impl Movies {
     /// ```
     pub fn reviews(&self, id: i64) -> Cursor<Review> {
         // build the url to query
-        let url = format!("{}/3/movie/{}/reviews", &self.host, id);
+        let url = format!("{}/3/movie/{}/reviews", &self.transport.host, id);
         // build our cursor
-        Cursor::new(url, &self.token)
+        Cursor::new(url, &self.transport)
     }
 
     /// Builds a cursor for movies to recommend based another movie
@@ -299,9 +719,9 @@ impl Movies {
     /// ```
     pub fn recommendations(&self, id: i64) -> Cursor<Movie> {
         // build the url to query
-        let url = format!("{}/3/movie/{}/recommendations", &self.host, id);
+        let url = format!("{}/3/movie/{}/recommendations", &self.transport.host, id);
         // build our cursor
-        Cursor::new(url, &self.token)
+        Cursor::new(url, &self.transport)
     }
 
     /// Builds a cursor for movies that are similar to a movie
@@ -328,9 +748,9 @@ impl Movies {
     /// ```
     pub fn similar(&self, id: i64) -> Cursor<Movie> {
         // build the url to query
-        let url = format!("{}/3/movie/{}/similar", &self.host, id);
+        let url = format!("{}/3/movie/{}/similar", &self.transport.host, id);
         // build our cursor
-        Cursor::new(url, &self.token)
+        Cursor::new(url, &self.transport)
     }
 
     /// Builds a cursor for movies that are popular
@@ -357,7 +777,7 @@ impl Movies {
     /// ```
     pub fn popular(&self) -> Cursor<Movie> {
         // build the url to query
-        let url = format!("{}/3/movie/popular", &self.host);
-        Cursor::new(url, &self.token)
+        let url = format!("{}/3/movie/popular", &self.transport.host);
+        Cursor::new(url, &self.transport).defaults(Defaults::LanguageAndRegion)
     }
 }
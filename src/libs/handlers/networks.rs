@@ -0,0 +1,145 @@
+use super::core::Transport;
+use super::{Join, ShowDiscover};
+use crate::libs::models::{AlternativeNames, Images, NetworkDetails};
+use crate::{get, Error};
+
+/// Handlers for TV network focused routes
+#[derive(Clone)]
+pub struct Networks {
+    /// The shared connection to tmdb
+    transport: Transport,
+}
+
+impl Networks {
+    /// Create a new Networks handler
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    pub(crate) fn new(transport: &Transport) -> Self {
+        // build networks handler
+        Networks {
+            transport: transport.clone(),
+        }
+    }
+
+    /// Get details on a TV network by id
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the network to retrieve details on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get details on a network
+    /// let network = tmdb.networks.details(49).await;
+    /// # assert!(network.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn details(&self, id: i64) -> Result<NetworkDetails, Error> {
+        // build url to query
+        let url = format!("{}/3/network/{}", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a NetworkDetails object from the response
+        get!(self, req)
+    }
+
+    /// Get the other names a TV network is known by
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the network to retrieve alternative names for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the other names for a network
+    /// let names = tmdb.networks.alternative_names(49).await;
+    /// # assert!(names.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn alternative_names(&self, id: i64) -> Result<AlternativeNames, Error> {
+        // build url to query
+        let url = format!(
+            "{}/3/network/{}/alternative_names",
+            &self.transport.host, id
+        );
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an AlternativeNames object from the response
+        get!(self, req)
+    }
+
+    /// Get the logos for a TV network
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the network to retrieve images for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the logos for a network
+    /// let images = tmdb.networks.images(49).await;
+    /// # assert!(images.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn images(&self, id: i64) -> Result<Images, Error> {
+        // build url to query
+        let url = format!("{}/3/network/{}/images", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an Images object from the response
+        get!(self, req)
+    }
+
+    /// Discover the TV shows that aired on a network
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the network to discover shows for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmdb_cli::{Client, ShowSort};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the most popular shows on a network
+    /// let shows = tmdb.networks.shows(49)
+    ///   .sort_by(ShowSort::PopularityDesc)
+    ///   .exec()
+    ///   .await;
+    /// # assert!(shows.is_ok())
+    /// # }
+    /// ```
+    pub fn shows(&self, id: i64) -> ShowDiscover<'_> {
+        ShowDiscover::new(&self.transport).with_networks(vec![id], Join::And)
+    }
+}
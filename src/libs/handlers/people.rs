@@ -0,0 +1,357 @@
+use futures::stream::BoxStream;
+
+use super::core::Transport;
+use super::Cursor;
+use crate::libs::models::{
+    ExternalIds, Person, PersonCombinedCredits, PersonDetails, PersonMovieCredits, PersonTvCredits,
+};
+use crate::{get, opt_param, Error};
+
+/// Person search cursor
+#[derive(Clone)]
+pub struct PersonSearch<'a> {
+    /// The url to use
+    url: String,
+    /// The handler being used to perform this search
+    handler: &'a People,
+    /// The current page of this search
+    pub page: u64,
+    /// The query in use
+    pub query: String,
+    /// The language to return results in
+    pub language: Option<String>,
+    /// Whether people known for adult films should be returned
+    pub adult: bool,
+}
+
+impl<'a> PersonSearch<'a> {
+    /// Search for people on the currently selected page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // search for a person
+    /// let search = tmdb.people.search("Michael Bay")
+    ///   .exec()
+    ///   .await;
+    /// # assert!(search.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn exec(self) -> Result<Cursor<Person>, Error> {
+        // load the requested page of results for this search
+        let mut cursor = self.cursor();
+        cursor.load().await?;
+        Ok(cursor)
+    }
+
+    /// Builds a cursor for this search without loading any results
+    ///
+    /// This is useful for setting stream limits on the cursor before streaming it.
+    pub fn cursor(mut self) -> Cursor<Person> {
+        // cast adult to a string
+        let adult = self.adult.to_string();
+        // build the url query params
+        let mut params: Vec<(String, String)> = Vec::with_capacity(2);
+        params.push(("query".into(), self.query));
+        params.push(("include_adult".into(), adult));
+        // add any optional params if they exist
+        opt_param!(params, "language", self.language);
+        // build cursor for this search
+        Cursor::new(self.url, &self.handler.transport)
+            .page(self.page)
+            .params(params)
+    }
+
+    /// Streams the people found by this search across all pages starting at the current page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // stream the first 40 results for our search
+    /// let people: Vec<_> = tmdb.people.search("Tom")
+    ///   .into_stream()
+    ///   .take(40)
+    ///   .collect()
+    ///   .await;
+    /// # assert!(people.iter().all(|res| res.is_ok()))
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<'static, Result<Person, Error>> {
+        self.cursor().into_stream()
+    }
+
+    /// Change the current page of our search
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page to query when this search is executed
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Sets the language to return results in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language to return results in
+    pub fn language<T: Into<String>>(mut self, lang: T) -> Self {
+        self.language = Some(lang.into());
+        self
+    }
+
+    /// Allows people known for adult films to be returned by this search
+    pub fn adult(mut self) -> Self {
+        self.adult = true;
+        self
+    }
+}
+
+/// Handlers for people focused routes
+#[derive(Clone)]
+pub struct People {
+    /// The shared connection to tmdb
+    transport: Transport,
+}
+
+impl People {
+    /// Create a new People handler
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    pub(crate) fn new(transport: &Transport) -> Self {
+        // build people handler
+        People {
+            transport: transport.clone(),
+        }
+    }
+
+    /// Search for a person
+    ///
+    /// # Arguments
+    ///
+    /// * `query` - The query to use when searching
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // search for a person
+    /// let search = tmdb.people.search("Michael Bay")
+    ///   .exec()
+    ///   .await;
+    /// # assert!(search.is_ok())
+    /// # }
+    /// ```
+    pub fn search<T: Into<String>>(&self, query: T) -> PersonSearch<'_> {
+        PersonSearch {
+            url: format!("{}/3/search/person", &self.transport.host),
+            handler: self,
+            // start at page 1 because tmdb doesn't use 0 based indexes
+            page: 1,
+            query: query.into(),
+            language: None,
+            adult: false,
+        }
+    }
+
+    /// Get details on a person by id
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person to retrieve details on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get details on a person
+    /// let person = tmdb.people.details(865).await;
+    /// # assert!(person.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn details(&self, id: i64) -> Result<PersonDetails, Error> {
+        // build url to query
+        let url = format!("{}/3/person/{}", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a PersonDetails object from the response
+        get!(self, req)
+    }
+
+    /// Get the movies a person has been in or worked on
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person to retrieve movie credits for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the movies a person has been in
+    /// let credits = tmdb.people.movie_credits(865).await;
+    /// # assert!(credits.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn movie_credits(&self, id: i64) -> Result<PersonMovieCredits, Error> {
+        // build url to query
+        let url = format!("{}/3/person/{}/movie_credits", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a PersonMovieCredits object from the response
+        get!(self, req)
+    }
+
+    /// Get the TV shows a person has been in or worked on
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person to retrieve tv credits for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the shows a person has been in
+    /// let credits = tmdb.people.tv_credits(17419).await;
+    /// # assert!(credits.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn tv_credits(&self, id: i64) -> Result<PersonTvCredits, Error> {
+        // build url to query
+        let url = format!("{}/3/person/{}/tv_credits", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a PersonTvCredits object from the response
+        get!(self, req)
+    }
+
+    /// Get the movies and TV shows a person has been in or worked on
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person to retrieve credits for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    /// use tmdb_cli::models::CombinedCastCredit;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get everything a person has been in
+    /// let credits = tmdb.people.combined_credits(17419).await.unwrap();
+    /// // print the titles of the movies they were in
+    /// for credit in &credits.cast {
+    ///   if let CombinedCastCredit::Movie(role) = credit {
+    ///     println!("{}", role.movie.title);
+    ///   }
+    /// }
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn combined_credits(&self, id: i64) -> Result<PersonCombinedCredits, Error> {
+        // build url to query
+        let url = format!("{}/3/person/{}/combined_credits", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a PersonCombinedCredits object from the response
+        get!(self, req)
+    }
+
+    /// Get the IDs for a person in other databases
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the person to retrieve external IDs for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the imdb and social media ids for a person
+    /// let ids = tmdb.people.external_ids(865).await;
+    /// # assert!(ids.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn external_ids(&self, id: i64) -> Result<ExternalIds, Error> {
+        // build url to query
+        let url = format!("{}/3/person/{}/external_ids", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an ExternalIds object from the response
+        get!(self, req)
+    }
+
+    /// Builds a cursor for people that are popular
+    ///
+    /// This lists refreshes daily.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get people that are popular
+    /// let popular = tmdb.people.popular().exec().await;
+    /// # assert!(popular.is_ok())
+    /// # }
+    /// ```
+    pub fn popular(&self) -> Cursor<Person> {
+        // build the url to query
+        let url = format!("{}/3/person/popular", &self.transport.host);
+        Cursor::new(url, &self.transport)
+    }
+}
@@ -0,0 +1,183 @@
+use super::core::{Defaults, Transport};
+use crate::libs::models::{WatchProvider, WatchProviderList, WatchRegion, WatchRegionList};
+use crate::{get, opt_param, Error};
+
+/// A request for every service that a kind of media can be watched on
+#[derive(Clone)]
+pub struct ProviderList<'a> {
+    /// The url to use
+    url: String,
+    /// The handler being used to perform this request
+    handler: &'a Providers,
+    /// The country to list services for
+    pub watch_region: Option<String>,
+    /// The language to get service names in
+    pub language: Option<String>,
+}
+
+impl<'a> ProviderList<'a> {
+    /// Get the services for the region and language set
+    ///
+    /// The clients default region and language are used if none were set.
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the services movies can be watched on in germany
+    /// let providers = tmdb.providers.movie()
+    ///   .watch_region("DE")
+    ///   .language("de-DE")
+    ///   .exec()
+    ///   .await;
+    /// # assert!(providers.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn exec(mut self) -> Result<Vec<WatchProvider>, Error> {
+        let transport = &self.handler.transport;
+        // this route filters by watch_region instead of region
+        if self.watch_region.is_none() {
+            self.watch_region = transport.region.clone();
+        }
+        // build the url query params
+        let mut params: Vec<(String, String)> = Vec::with_capacity(2);
+        opt_param!(params, "watch_region", self.watch_region);
+        opt_param!(params, "language", self.language);
+        // build a request using the our token and query
+        let req = transport.get_with(&self.url, params, Defaults::Language);
+        // send request and build a WatchProviderList object from the response
+        let providers: WatchProviderList = get!(self.handler, req)?;
+        Ok(providers.results)
+    }
+
+    /// Sets the country to list services for
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The ISO 3166-1 code of the country such as `US`
+    pub fn watch_region<T: Into<String>>(mut self, region: T) -> Self {
+        self.watch_region = Some(region.into());
+        self
+    }
+
+    /// Sets the language to get service names in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language to use such as `en-US`
+    pub fn language<T: Into<String>>(mut self, lang: T) -> Self {
+        self.language = Some(lang.into());
+        self
+    }
+}
+
+/// Handlers for watch provider routes
+#[derive(Clone)]
+pub struct Providers {
+    /// The shared connection to tmdb
+    transport: Transport,
+}
+
+impl Providers {
+    /// Create a new Providers handler
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    pub(crate) fn new(transport: &Transport) -> Self {
+        // build providers handler
+        Providers {
+            transport: transport.clone(),
+        }
+    }
+
+    /// Builds a request for every service that movies can be watched on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the services movies can be watched on
+    /// let providers = tmdb.providers.movie().exec().await;
+    /// # assert!(providers.is_ok())
+    /// # }
+    /// ```
+    pub fn movie(&self) -> ProviderList<'_> {
+        self.list("movie")
+    }
+
+    /// Builds a request for every service that TV shows can be watched on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the services shows can be watched on
+    /// let providers = tmdb.providers.tv().exec().await;
+    /// # assert!(providers.is_ok())
+    /// # }
+    /// ```
+    pub fn tv(&self) -> ProviderList<'_> {
+        self.list("tv")
+    }
+
+    /// Get every country that tmdb has watch providers for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // print the countries we can find providers in
+    /// for region in tmdb.providers.regions().await.unwrap() {
+    ///   println!("{}: {}", region.iso_3166_1, region.english_name);
+    /// }
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn regions(&self) -> Result<Vec<WatchRegion>, Error> {
+        // build url to query
+        let url = format!("{}/3/watch/providers/regions", &self.transport.host);
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a WatchRegionList object from the response
+        let regions: WatchRegionList = get!(self, req)?;
+        Ok(regions.results)
+    }
+
+    /// Builds a request for every service that a kind of media can be watched on
+    ///
+    /// # Arguments
+    ///
+    /// * `kind` - The kind of media to get providers for
+    fn list(&self, kind: &str) -> ProviderList<'_> {
+        // build url to query
+        let url = format!("{}/3/watch/providers/{}", &self.transport.host, kind);
+        ProviderList {
+            url,
+            handler: self,
+            watch_region: None,
+            language: None,
+        }
+    }
+}
@@ -0,0 +1,133 @@
+use rand::Rng;
+use std::time::Duration;
+
+use crate::Error;
+
+/// How failed requests should be retried
+///
+/// Requests are retried when tmdb rate limits us, when tmdb returns a 5xx, or
+/// when the connection fails. Rate limited requests wait for as long as tmdb
+/// asks in its Retry-After header while all others back off exponentially
+/// with jitter. If tmdb asks us to wait longer than the max delay the
+/// [`Error::RateLimited`] is returned instead so callers can decide what to do.
+///
+/// # Examples
+///
+/// ```
+/// use std::time::Duration;
+/// use tmdb_cli::{Client, RetryPolicy};
+///
+/// let retry = RetryPolicy::new(5)
+///   .base_delay(Duration::from_millis(250))
+///   .max_delay(Duration::from_secs(10));
+/// let tmdb = Client::builder("TMDB_TOKEN")
+///   .retry(retry)
+///   .build()
+///   .unwrap();
+/// ```
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    /// The max number of times to send a request including the first attempt
+    pub max_attempts: u32,
+    /// The delay to wait before the first retry
+    pub base_delay: Duration,
+    /// The longest we will back off or wait for a Retry-After between retries
+    pub max_delay: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        RetryPolicy {
+            max_attempts: 4,
+            base_delay: Duration::from_millis(500),
+            max_delay: Duration::from_secs(30),
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// Creates a new retry policy with the default delays
+    ///
+    /// # Arguments
+    ///
+    /// * `max_attempts` - The max number of times to send a request including the first attempt
+    pub fn new(max_attempts: u32) -> Self {
+        RetryPolicy {
+            max_attempts,
+            ..Default::default()
+        }
+    }
+
+    /// Creates a retry policy that never retries
+    pub fn none() -> Self {
+        Self::new(1)
+    }
+
+    /// Sets the delay to wait before the first retry
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - The delay to start backing off from
+    pub fn base_delay(mut self, delay: Duration) -> Self {
+        self.base_delay = delay;
+        self
+    }
+
+    /// Sets the longest we will back off between retries
+    ///
+    /// # Arguments
+    ///
+    /// * `delay` - The max delay to back off for
+    pub fn max_delay(mut self, delay: Duration) -> Self {
+        self.max_delay = delay;
+        self
+    }
+
+    /// Whether a failed request should be retried
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The attempt that just failed starting at 1
+    /// * `err` - The error this attempt failed with
+    pub(crate) fn should_retry(&self, attempt: u32, err: &Error) -> bool {
+        if attempt >= self.max_attempts {
+            return false;
+        }
+        match err {
+            // don't silently stall for longer than we would ever back off
+            Error::RateLimited {
+                retry_after: Some(wait),
+            } => *wait <= self.max_delay,
+            Error::RateLimited { .. } => true,
+            Error::Api { status, .. } => *status >= 500,
+            Error::Transport(err) => err.is_connect() || err.is_timeout() || err.is_request(),
+            _ => false,
+        }
+    }
+
+    /// How long to wait before retrying a failed request
+    ///
+    /// # Arguments
+    ///
+    /// * `attempt` - The attempt that just failed starting at 1
+    /// * `err` - The error this attempt failed with
+    pub(crate) fn delay(&self, attempt: u32, err: &Error) -> Duration {
+        // respect how long tmdb asks us to wait up to our max delay
+        if let Error::RateLimited {
+            retry_after: Some(wait),
+        } = err
+        {
+            return (*wait).min(self.max_delay);
+        }
+        // double our delay for each failed attempt without overflowing
+        let factor = 2u32.saturating_pow(attempt.saturating_sub(1));
+        let backoff = self
+            .base_delay
+            .checked_mul(factor)
+            .map_or(self.max_delay, |delay| delay.min(self.max_delay));
+        // jitter our delay so parallel workers don't retry in lockstep
+        let millis = backoff.as_millis() as u64;
+        let jitter = rand::thread_rng().gen_range(0, millis / 2 + 1);
+        Duration::from_millis(millis - jitter)
+    }
+}
@@ -0,0 +1,151 @@
+use futures::stream::BoxStream;
+
+use super::core::Transport;
+use super::Cursor;
+use crate::libs::models::MediaItem;
+use crate::{opt_param, Error};
+
+/// Multi search cursor for movies, TV shows and people at once
+#[derive(Clone)]
+pub struct MultiSearch<'a> {
+    /// The url to use
+    url: String,
+    /// The shared connection to tmdb
+    transport: &'a Transport,
+    /// The current page of this search
+    pub page: u64,
+    /// The query in use
+    pub query: String,
+    /// The region to search in
+    pub region: Option<String>,
+    /// The language to return results in
+    pub language: Option<String>,
+    /// Whether adult results should be returned
+    pub adult: bool,
+}
+
+impl<'a> MultiSearch<'a> {
+    /// Create a new multi search
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    /// * `query` - The query to use when searching
+    pub(super) fn new(transport: &'a Transport, query: String) -> Self {
+        MultiSearch {
+            url: format!("{}/3/search/multi", &transport.host),
+            transport,
+            // start at page 1 because tmdb doesn't use 0 based indexes
+            page: 1,
+            query,
+            region: None,
+            language: None,
+            adult: false,
+        }
+    }
+
+    /// Search for movies, shows and people on the currently selected page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // search for anything matching our query
+    /// let search = tmdb.search_multi("Red")
+    ///   .exec()
+    ///   .await;
+    /// # assert!(search.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn exec(self) -> Result<Cursor<MediaItem>, Error> {
+        // load the requested page of results for this search
+        let mut cursor = self.cursor();
+        cursor.load().await?;
+        Ok(cursor)
+    }
+
+    /// Builds a cursor for this search without loading any results
+    ///
+    /// This is useful for setting stream limits on the cursor before streaming it.
+    pub fn cursor(mut self) -> Cursor<MediaItem> {
+        // cast adult to a string
+        let adult = self.adult.to_string();
+        // build the url query params
+        let mut params: Vec<(String, String)> = Vec::with_capacity(2);
+        params.push(("query".into(), self.query));
+        params.push(("include_adult".into(), adult));
+        // add any optional params if they exist
+        opt_param!(params, "region", self.region);
+        opt_param!(params, "language", self.language);
+        // build cursor for this search
+        Cursor::new(self.url, self.transport)
+            .page(self.page)
+            .params(params)
+    }
+
+    /// Streams the results of this search across all pages starting at the current page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // print the titles of the first 40 results for our search
+    /// let mut stream = tmdb.search_multi("Red").into_stream().take(40);
+    /// while let Some(item) = stream.next().await {
+    ///   let item = item.unwrap();
+    ///   println!("{} ({:?})", item.title(), item.year());
+    /// }
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<'static, Result<MediaItem, Error>> {
+        self.cursor().into_stream()
+    }
+
+    /// Change the current page of our search
+    ///
+    /// # Arguments
+    ///
+    /// * `page` - The page to query when this search is executed
+    pub fn page(mut self, page: u64) -> Self {
+        self.page = page;
+        self
+    }
+
+    /// Sets the region to search in
+    ///
+    /// # Arguments
+    ///
+    /// * `region` - The ISO 3166-1 region code to search in such as `US`
+    pub fn region<T: Into<String>>(mut self, region: T) -> Self {
+        self.region = Some(region.into());
+        self
+    }
+
+    /// Sets the language to return results in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language to return results in
+    pub fn language<T: Into<String>>(mut self, lang: T) -> Self {
+        self.language = Some(lang.into());
+        self
+    }
+
+    /// Allows adult results to be returned by this search
+    pub fn adult(mut self) -> Self {
+        self.adult = true;
+        self
+    }
+}
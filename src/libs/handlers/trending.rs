@@ -0,0 +1,111 @@
+use super::core::Transport;
+use super::Cursor;
+use crate::libs::models::MediaItem;
+
+/// The kind of media to get trending items for
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum MediaType {
+    /// Movies, TV shows and people
+    All,
+    /// Only movies
+    Movie,
+    /// Only TV shows
+    Tv,
+    /// Only people
+    Person,
+}
+
+impl MediaType {
+    /// Get the value tmdb expects for this media type
+    pub fn as_str(self) -> &'static str {
+        match self {
+            MediaType::All => "all",
+            MediaType::Movie => "movie",
+            MediaType::Tv => "tv",
+            MediaType::Person => "person",
+        }
+    }
+}
+
+/// The window of time to get trending items over
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum TimeWindow {
+    /// Items trending today
+    Day,
+    /// Items trending this week
+    Week,
+}
+
+impl TimeWindow {
+    /// Get the value tmdb expects for this time window
+    pub fn as_str(self) -> &'static str {
+        match self {
+            TimeWindow::Day => "day",
+            TimeWindow::Week => "week",
+        }
+    }
+}
+
+/// Handlers for trending routes
+#[derive(Clone)]
+pub struct Trending {
+    /// The shared connection to tmdb
+    transport: Transport,
+}
+
+impl Trending {
+    /// Create a new Trending handler
+    ///
+    /// # Arguments
+    ///
+    /// * `transport` - The shared connection to tmdb
+    pub(crate) fn new(transport: &Transport) -> Self {
+        // build trending handler
+        Trending {
+            transport: transport.clone(),
+        }
+    }
+
+    /// Builds a cursor for the items that are trending
+    ///
+    /// # Arguments
+    ///
+    /// * `media_type` - The kind of media to get trending items for
+    /// * `window` - The window of time to get trending items over
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmdb_cli::models::MediaItem;
+    /// use tmdb_cli::{Client, MediaType, TimeWindow};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get everything that is trending today
+    /// let trending = tmdb.trending.list(MediaType::All, TimeWindow::Day)
+    ///   .exec()
+    ///   .await
+    ///   .unwrap();
+    /// // print the names of what is trending
+    /// for item in &trending.results {
+    ///   match item {
+    ///     MediaItem::Movie(movie) => println!("movie: {}", movie.title),
+    ///     MediaItem::Show(show) => println!("show: {}", show.name),
+    ///     MediaItem::Person(person) => println!("person: {}", person.name),
+    ///   }
+    /// }
+    /// # }
+    /// ```
+    pub fn list(&self, media_type: MediaType, window: TimeWindow) -> Cursor<MediaItem> {
+        // build the url to query
+        let url = format!(
+            "{}/3/trending/{}/{}",
+            &self.transport.host,
+            media_type.as_str(),
+            window.as_str()
+        );
+        Cursor::new(url, &self.transport)
+    }
+}
@@ -1,6 +1,14 @@
-use super::Cursor;
-use crate::libs::models::{Credits, Show, ShowDetails, Review};
-use crate::{client, get, opt_param};
+use futures::stream::BoxStream;
+use std::collections::HashMap;
+
+use super::core::{Defaults, Transport};
+use super::{Cursor, ShowDiscover};
+use crate::libs::models::{
+    ContentRatings, CountryCode, Credits, EpisodeCredits, EpisodeDetails, ExternalIds, Images,
+    RegionProviders, Review, SeasonDetails, Show, ShowDetails, ShowDetailsBundle, Video, Videos,
+    WatchProviders,
+};
+use crate::{get, opt_param, Error};
 
 /// Show search cursor
 #[derive(Clone)]
@@ -43,7 +51,17 @@ impl<'a> ShowSearch<'a> {
     /// # }
     /// ```
     #[syncwrap::wrap]
-    pub async fn exec(mut self) -> Result<Cursor<Show>, reqwest::Error> {
+    pub async fn exec(self) -> Result<Cursor<Show>, Error> {
+        // load the requested page of results for this search
+        let mut cursor = self.cursor();
+        cursor.load().await?;
+        Ok(cursor)
+    }
+
+    /// Builds a cursor for this search without loading any results
+    ///
+    /// This is useful for setting stream limits on the cursor before streaming it.
+    pub fn cursor(mut self) -> Cursor<Show> {
         // cast page to a string
         let adult = self.adult.to_string();
         // build the url query params
@@ -54,11 +72,35 @@ impl<'a> ShowSearch<'a> {
         opt_param!(params, "language", self.language);
         opt_param!(params, "first_air_date_year", self.year);
         // build cursor for this search
-        Cursor::new(self.url, &self.handler.token)
+        Cursor::new(self.url, &self.handler.transport)
             .page(self.page)
             .params(params)
-            .next_page()
-            .await
+    }
+
+    /// Streams the shows found by this search across all pages starting at the current page
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use futures::StreamExt;
+    /// use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // stream the first 40 results for our search
+    /// let shows: Vec<_> = tmdb.tv.search("Red Vs. Blue")
+    ///   .year(2003)
+    ///   .into_stream()
+    ///   .take(40)
+    ///   .collect()
+    ///   .await;
+    /// # assert!(shows.iter().all(|res| res.is_ok()))
+    /// # }
+    /// ```
+    pub fn into_stream(self) -> BoxStream<'static, Result<Show, Error>> {
+        self.cursor().into_stream()
     }
 
     /// Change the current page of our search
@@ -98,15 +140,132 @@ impl<'a> ShowSearch<'a> {
     }
 }
 
+/// A request for details on a show with extra sections appended
+///
+/// Each appended section is fetched in the same request using `append_to_response`.
+#[derive(Clone)]
+pub struct ShowDetailsWith<'a> {
+    /// The ID of the show to get details on
+    id: i64,
+    /// The handler being used to perform this request
+    handler: &'a Tv,
+    /// The sections to append to this request
+    pub sections: Vec<&'static str>,
+    /// The language to get details in
+    pub language: Option<String>,
+    /// The extra languages to include appended videos in
+    pub video_languages: Vec<String>,
+}
+
+impl<'a> ShowDetailsWith<'a> {
+    /// Get the details and any appended sections for this show
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get details, credits and videos in a single request
+    /// let bundle = tmdb.tv.details_with(39373)
+    ///   .credits()
+    ///   .videos()
+    ///   .exec()
+    ///   .await;
+    /// # assert!(bundle.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn exec(mut self) -> Result<ShowDetailsBundle, Error> {
+        // build url to query
+        let url = format!("{}/3/tv/{}", &self.handler.transport.host, self.id);
+        // build the url query params
+        let mut params: Vec<(String, String)> = Vec::with_capacity(2);
+        if !self.sections.is_empty() {
+            params.push(("append_to_response".into(), self.sections.join(",")));
+        }
+        opt_param!(params, "language", self.language);
+        if !self.video_languages.is_empty() {
+            params.push((
+                "include_video_language".into(),
+                self.video_languages.join(","),
+            ));
+        }
+        // build a request using the our token and query
+        let req = self
+            .handler
+            .transport
+            .get_with(&url, params, Defaults::Language);
+        // send request and build a ShowDetailsBundle object from the response
+        get!(self.handler, req)
+    }
+
+    /// Adds a section to append if it hasn't been added already
+    ///
+    /// # Arguments
+    ///
+    /// * `section` - The section to append
+    fn append(mut self, section: &'static str) -> Self {
+        if !self.sections.contains(&section) {
+            self.sections.push(section);
+        }
+        self
+    }
+
+    /// Appends the cast and crew to this request
+    pub fn credits(self) -> Self {
+        self.append("credits")
+    }
+
+    /// Appends the videos to this request
+    pub fn videos(self) -> Self {
+        self.append("videos")
+    }
+
+    /// Appends the images to this request
+    pub fn images(self) -> Self {
+        self.append("images")
+    }
+
+    /// Appends the IDs in other databases to this request
+    pub fn external_ids(self) -> Self {
+        self.append("external_ids")
+    }
+
+    /// Appends the content ratings to this request
+    pub fn content_ratings(self) -> Self {
+        self.append("content_ratings")
+    }
+
+    /// Sets the language to get details in
+    ///
+    /// # Arguments
+    ///
+    /// * `lang` - The language to get details in
+    pub fn language<T: Into<String>>(mut self, lang: T) -> Self {
+        self.language = Some(lang.into());
+        self
+    }
+
+    /// Sets the extra languages to include appended videos in
+    ///
+    /// # Arguments
+    ///
+    /// * `languages` - The languages to include such as `en` or `null` for videos without a language
+    pub fn video_languages(mut self, languages: &[&str]) -> Self {
+        self.video_languages = languages.iter().map(|lang| lang.to_string()).collect();
+        self
+    }
+}
+
 /// Handlers for TV show focused routes
 #[derive(Clone)]
 pub struct Tv {
-    /// The URL/ip to reach tmdb at
-    host: String,
-    /// A reqwest client object
-    pub client: reqwest::Client,
-    /// A token to use when authenticating
-    pub token: String,
+    /// The shared connection to tmdb
+    transport: Transport,
 }
 
 impl Tv {
@@ -114,16 +273,11 @@ impl Tv {
     ///
     /// # Arguments
     ///
-    /// * `host` - The host/url tmdb is at
-    /// * `token` - The token to use when authenticating
-    pub fn new(host: &str, token: &str) -> Self {
-        // build client
-        let client = client!();
+    /// * `transport` - The shared connection to tmdb
+    pub(crate) fn new(transport: &Transport) -> Self {
         // build shows handler
         Tv {
-            host: host.to_owned(),
-            client,
-            token: token.to_owned(),
+            transport: transport.clone(),
         }
     }
 
@@ -150,9 +304,9 @@ impl Tv {
     /// # assert!(search.is_ok())
     /// # }
     /// ```
-    pub fn search<T: Into<String>>(&self, query: T) -> ShowSearch {
+    pub fn search<T: Into<String>>(&self, query: T) -> ShowSearch<'_> {
         ShowSearch {
-            url: format!("{}/3/search/tv", &self.host),
+            url: format!("{}/3/search/tv", &self.transport.host),
             handler: self,
             // start at page 1 because tmdb doesn't use 0 based indexes
             page: 1,
@@ -163,6 +317,29 @@ impl Tv {
         }
     }
 
+    /// Discover shows by filtering the whole catalog
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// use tmdb_cli::{Client, Join};
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // discover shows in a genre
+    /// let discover = tmdb.tv.discover()
+    ///   .genres(vec![18], Join::And)
+    ///   .exec()
+    ///   .await;
+    /// # assert!(discover.is_ok())
+    /// # }
+    /// ```
+    pub fn discover(&self) -> ShowDiscover<'_> {
+        ShowDiscover::new(&self.transport)
+    }
+
     /// Get details on a show by id
     ///
     /// # Arguments
@@ -184,13 +361,48 @@ impl Tv {
     /// # }
     /// ```
     #[syncwrap::wrap]
-    pub async fn details(&self, id: i64) -> Result<ShowDetails, reqwest::Error> {
+    pub async fn details(&self, id: i64) -> Result<ShowDetails, Error> {
         // build url to query
-        let url = format!("{}/3/tv/{}", &self.host, id);
+        let url = format!("{}/3/tv/{}", &self.transport.host, id);
         // build a request using the our token and query
-        let req = self.client.get(&url).query(&[("api_key", &self.token)]);
+        let req = self.transport.get_localized(&url);
         // send request and build a ShowDetails object from the response
-        get!(self, req)?.json::<ShowDetails>().await
+        get!(self, req)
+    }
+
+    /// Get details on a show along with extra sections in a single request
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show to retrieve details on
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get details, credits, images and external ids in a single request
+    /// let bundle = tmdb.tv.details_with(39373)
+    ///   .credits()
+    ///   .images()
+    ///   .external_ids()
+    ///   .exec()
+    ///   .await;
+    /// # assert!(bundle.is_ok())
+    /// # }
+    /// ```
+    pub fn details_with(&self, id: i64) -> ShowDetailsWith<'_> {
+        ShowDetailsWith {
+            id,
+            handler: self,
+            sections: Vec::new(),
+            language: None,
+            video_languages: Vec::new(),
+        }
     }
 
     /// Get the credis for a show by id
@@ -214,13 +426,514 @@ impl Tv {
     /// # }
     /// ```
     #[syncwrap::wrap]
-    pub async fn credits(&self, id: i64) -> Result<Credits, reqwest::Error> {
+    pub async fn credits(&self, id: i64) -> Result<Credits, Error> {
+        // build url to query
+        let url = format!("{}/3/tv/{}/credits", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a Credits object from the response
+        get!(self, req)
+    }
+
+    /// Get the IDs for a show in other databases
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show to retrieve external IDs for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the imdb and tvdb ids for a show
+    /// let ids = tmdb.tv.external_ids(39373).await;
+    /// # assert!(ids.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn external_ids(&self, id: i64) -> Result<ExternalIds, Error> {
+        // build url to query
+        let url = format!("{}/3/tv/{}/external_ids", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an ExternalIds object from the response
+        get!(self, req)
+    }
+
+    /// Get the posters, backdrops and logos for a show
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show to retrieve images for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the images for a show
+    /// let images = tmdb.tv.images(39373).await;
+    /// # assert!(images.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn images(&self, id: i64) -> Result<Images, Error> {
+        // build url to query
+        let url = format!("{}/3/tv/{}/images", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build an Images object from the response
+        get!(self, req)
+    }
+
+    /// Get the content ratings of a show in each country
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show to retrieve content ratings for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the content ratings for a show
+    /// let content_ratings = tmdb.tv.content_ratings(1399).await;
+    /// # assert!(content_ratings.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn content_ratings(&self, id: i64) -> Result<ContentRatings, Error> {
+        // build url to query
+        let url = format!("{}/3/tv/{}/content_ratings", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a ContentRatings object from the response
+        get!(self, req)
+    }
+
+    /// Get where a show can be watched in each country
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show to find watch providers for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // print where a show can be streamed in the US
+    /// let providers = tmdb.tv.watch_providers(1399).await.unwrap();
+    /// if let Some(us) = providers.get("US") {
+    ///   for provider in &us.flatrate {
+    ///     println!("{}", provider.provider_name);
+    ///   }
+    /// }
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn watch_providers(
+        &self,
+        id: i64,
+    ) -> Result<HashMap<CountryCode, RegionProviders>, Error> {
+        // build url to query
+        let url = format!("{}/3/tv/{}/watch/providers", &self.transport.host, id);
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a WatchProviders object from the response
+        let providers: WatchProviders = get!(self, req)?;
+        Ok(providers.results)
+    }
+
+    /// Get the videos such as trailers for a show
+    ///
+    /// Only videos in the language results are returned in are included. Use
+    /// [`Tv::videos_in_languages`] to get videos in other languages.
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show to retrieve videos for
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the videos for a show
+    /// let videos = tmdb.tv.videos(39373).await;
+    /// # assert!(videos.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn videos(&self, id: i64) -> Result<Vec<Video>, Error> {
+        self.load_videos(id, &[]).await
+    }
+
+    /// Get the videos such as trailers for a show in extra languages
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show to retrieve videos for
+    /// * `languages` - The extra languages to include videos in such as `en` or `null` for videos without a language
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    /// use tmdb_cli::models::Video;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the videos for a show in english or without a language
+    /// let videos = tmdb.tv.videos_in_languages(39373, &["en", "null"]).await.unwrap();
+    /// let trailer = Video::best_trailer(&videos, &["en"]);
+    /// # assert!(trailer.is_some())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn videos_in_languages(
+        &self,
+        id: i64,
+        languages: &[&str],
+    ) -> Result<Vec<Video>, Error> {
+        self.load_videos(id, languages).await
+    }
+
+    /// Get the videos for a show optionally including extra languages
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show to retrieve videos for
+    /// * `languages` - The extra languages to include videos in
+    async fn load_videos(&self, id: i64, languages: &[&str]) -> Result<Vec<Video>, Error> {
+        // build url to query
+        let url = format!("{}/3/tv/{}/videos", &self.transport.host, id);
+        // build a request using the our token and query
+        let mut params: Vec<(String, String)> = Vec::with_capacity(1);
+        if !languages.is_empty() {
+            params.push(("include_video_language".into(), languages.join(",")));
+        }
+        let req = self.transport.get_with(&url, params, Defaults::Language);
+        // send request and build a Videos object from the response
+        let videos: Videos = get!(self, req)?;
+        Ok(videos.results)
+    }
+
+    /// Get details on a season of a show including all of its episodes
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show the season is from
+    /// * `season` - The number of the season
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the first season of a show
+    /// let season = tmdb.tv.season(1399, 1).await;
+    /// # assert!(season.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn season(&self, id: i64, season: u64) -> Result<SeasonDetails, Error> {
+        // build url to query
+        let url = format!("{}/3/tv/{}/season/{}", &self.transport.host, id, season);
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a SeasonDetails object from the response
+        get!(self, req)
+    }
+
+    /// Get the credits for a season of a show
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show the season is from
+    /// * `season` - The number of the season
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the credits for a season
+    /// let credits = tmdb.tv.season_credits(1399, 1).await;
+    /// # assert!(credits.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn season_credits(&self, id: i64, season: u64) -> Result<Credits, Error> {
         // build url to query
-        let url = format!("{}/3/tv/{}/credits", &self.host, id);
+        let url = format!(
+            "{}/3/tv/{}/season/{}/credits",
+            &self.transport.host, id, season
+        );
         // build a request using the our token and query
-        let req = self.client.get(&url).query(&[("api_key", &self.token)]);
+        let req = self.transport.get_localized(&url);
         // send request and build a Credits object from the response
-        get!(self, req)?.json::<Credits>().await
+        get!(self, req)
+    }
+
+    /// Get the posters for a season of a show
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show the season is from
+    /// * `season` - The number of the season
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the posters for a season
+    /// let images = tmdb.tv.season_images(1399, 1).await;
+    /// # assert!(images.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn season_images(&self, id: i64, season: u64) -> Result<Images, Error> {
+        // build url to query
+        let url = format!(
+            "{}/3/tv/{}/season/{}/images",
+            &self.transport.host, id, season
+        );
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a Images object from the response
+        get!(self, req)
+    }
+
+    /// Get the IDs for a season of a show in other databases
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show the season is from
+    /// * `season` - The number of the season
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the external ids for a season
+    /// let ids = tmdb.tv.season_external_ids(1399, 1).await;
+    /// # assert!(ids.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn season_external_ids(&self, id: i64, season: u64) -> Result<ExternalIds, Error> {
+        // build url to query
+        let url = format!(
+            "{}/3/tv/{}/season/{}/external_ids",
+            &self.transport.host, id, season
+        );
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a ExternalIds object from the response
+        get!(self, req)
+    }
+
+    /// Get details on an episode of a show including its guest stars and crew
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show the episode is from
+    /// * `season` - The number of the season
+    /// * `episode` - The number of the episode in its season
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the first episode of a show
+    /// let episode = tmdb.tv.episode(1399, 1, 1).await;
+    /// # assert!(episode.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn episode(
+        &self,
+        id: i64,
+        season: u64,
+        episode: u64,
+    ) -> Result<EpisodeDetails, Error> {
+        // build url to query
+        let url = format!(
+            "{}/3/tv/{}/season/{}/episode/{}",
+            &self.transport.host, id, season, episode
+        );
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a EpisodeDetails object from the response
+        get!(self, req)
+    }
+
+    /// Get the credits for an episode of a show
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show the episode is from
+    /// * `season` - The number of the season
+    /// * `episode` - The number of the episode in its season
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the credits for an episode
+    /// let credits = tmdb.tv.episode_credits(1399, 1, 1).await;
+    /// # assert!(credits.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn episode_credits(
+        &self,
+        id: i64,
+        season: u64,
+        episode: u64,
+    ) -> Result<EpisodeCredits, Error> {
+        // build url to query
+        let url = format!(
+            "{}/3/tv/{}/season/{}/episode/{}/credits",
+            &self.transport.host, id, season, episode
+        );
+        // build a request using the our token and query
+        let req = self.transport.get_localized(&url);
+        // send request and build a EpisodeCredits object from the response
+        get!(self, req)
+    }
+
+    /// Get the stills for an episode of a show
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show the episode is from
+    /// * `season` - The number of the season
+    /// * `episode` - The number of the episode in its season
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the stills for an episode
+    /// let images = tmdb.tv.episode_images(1399, 1, 1).await;
+    /// # assert!(images.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn episode_images(
+        &self,
+        id: i64,
+        season: u64,
+        episode: u64,
+    ) -> Result<Images, Error> {
+        // build url to query
+        let url = format!(
+            "{}/3/tv/{}/season/{}/episode/{}/images",
+            &self.transport.host, id, season, episode
+        );
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a Images object from the response
+        get!(self, req)
+    }
+
+    /// Get the IDs for an episode of a show in other databases
+    ///
+    /// # Arguments
+    ///
+    /// * `id` - The ID of the show the episode is from
+    /// * `season` - The number of the season
+    /// * `episode` - The number of the episode in its season
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the external ids for an episode
+    /// let ids = tmdb.tv.episode_external_ids(1399, 1, 1).await;
+    /// # assert!(ids.is_ok())
+    /// # }
+    /// ```
+    #[syncwrap::wrap]
+    pub async fn episode_external_ids(
+        &self,
+        id: i64,
+        season: u64,
+        episode: u64,
+    ) -> Result<ExternalIds, Error> {
+        // build url to query
+        let url = format!(
+            "{}/3/tv/{}/season/{}/episode/{}/external_ids",
+            &self.transport.host, id, season, episode
+        );
+        // build a request using the our token and query
+        let req = self.transport.get(&url);
+        // send request and build a ExternalIds object from the response
+        get!(self, req)
     }
 
     /// Builds a cursor for reviews for a tv show
@@ -245,9 +958,9 @@ impl Tv {
     /// ```
     pub fn reviews(&self, id: i64) -> Cursor<Review> {
         // build the url to query
-        let url = format!("{}/3/tv/{}/reviews", &self.host, id);
+        let url = format!("{}/3/tv/{}/reviews", &self.transport.host, id);
         // build our cursor
-        Cursor::new(url, &self.token)
+        Cursor::new(url, &self.transport)
     }
 
     /// Builds a cursor for shows to recommend based another tv show
@@ -272,9 +985,9 @@ impl Tv {
     /// ```
     pub fn recommendations(&self, id: i64) -> Cursor<Show> {
         // build the url to query
-        let url = format!("{}/3/tv/{}/recommendations", &self.host, id);
+        let url = format!("{}/3/tv/{}/recommendations", &self.transport.host, id);
         // build our cursor
-        Cursor::new(url, &self.token)
+        Cursor::new(url, &self.transport)
     }
 
     /// Builds a cursor for shows that are similar to a tv show
@@ -301,9 +1014,9 @@ impl Tv {
     /// ```
     pub fn similar(&self, id: i64) -> Cursor<Show> {
         // build the url to query
-        let url = format!("{}/3/tv/{}/similar", &self.host, id);
+        let url = format!("{}/3/tv/{}/similar", &self.transport.host, id);
         // build our cursor
-        Cursor::new(url, &self.token)
+        Cursor::new(url, &self.transport)
     }
 
     /// Builds a cursor for shows that are popular
@@ -330,7 +1043,7 @@ impl Tv {
     /// ```
     pub fn popular(&self) -> Cursor<Show> {
         // build the url to query
-        let url = format!("{}/3/tv/popular", &self.host);
-        Cursor::new(url, &self.token)
+        let url = format!("{}/3/tv/popular", &self.transport.host);
+        Cursor::new(url, &self.transport)
     }
 }
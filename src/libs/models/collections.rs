@@ -0,0 +1,87 @@
+use serde::{Deserialize, Serialize};
+
+use super::helpers::image_urls;
+use super::{BackdropSize, Movie, PosterSize};
+
+/// A brief summary of a collection of movies such as a franchise
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CollectionSummary {
+    /// The id for this collection
+    pub id: i64,
+    /// The name of this collection
+    pub name: String,
+    /// A brief overview of this collection if tmdb included it
+    pub overview: Option<String>,
+    /// The path to the poster for this collection
+    pub poster_path: Option<String>,
+    /// The path to the backdrop for this collection
+    pub backdrop_path: Option<String>,
+}
+
+image_urls!(CollectionSummary {
+    poster_url(poster_path, PosterSize),
+    backdrop_url(backdrop_path, BackdropSize),
+});
+
+/// A collection of movies such as a franchise
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Collection {
+    /// The id for this collection
+    pub id: i64,
+    /// The name of this collection
+    pub name: String,
+    /// A brief overview of this collection
+    #[serde(default)]
+    pub overview: String,
+    /// The path to the poster for this collection
+    pub poster_path: Option<String>,
+    /// The path to the backdrop for this collection
+    pub backdrop_path: Option<String>,
+    /// The movies in this collection
+    #[serde(default)]
+    pub parts: Vec<Movie>,
+}
+
+image_urls!(Collection {
+    poster_url(poster_path, PosterSize),
+    backdrop_url(backdrop_path, BackdropSize),
+});
+
+/// The translated text for a collection
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct TranslationData {
+    /// The translated name
+    #[serde(default)]
+    pub title: String,
+    /// The translated overview
+    #[serde(default)]
+    pub overview: String,
+    /// The homepage for this language
+    #[serde(default)]
+    pub homepage: String,
+}
+
+/// A translation of a collection into a single language
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Translation {
+    /// The country this translation is for
+    pub iso_3166_1: String,
+    /// The language this translation is in
+    pub iso_639_1: String,
+    /// The name of this language in itself
+    pub name: String,
+    /// The english name of this language
+    pub english_name: String,
+    /// The translated text
+    pub data: TranslationData,
+}
+
+/// The translations of a collection
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Translations {
+    /// The id of what these translations are for
+    pub id: Option<i64>,
+    /// The translations that were found
+    #[serde(default)]
+    pub translations: Vec<Translation>,
+}
@@ -0,0 +1,70 @@
+use serde::{Deserialize, Serialize};
+
+use super::helpers::image_urls;
+use super::LogoSize;
+
+/// Details from searching for a [`Company`] by name
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Company {
+    /// The id for this company
+    pub id: i64,
+    /// The name of this company
+    pub name: String,
+    /// The path to the logo for this company
+    pub logo_path: Option<String>,
+    /// The country of origin for this company if tmdb included it
+    pub origin_country: Option<String>,
+}
+
+image_urls!(Company {
+    logo_url(logo_path, LogoSize),
+});
+
+/// Details about a company that produces movies and TV shows
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CompanyDetails {
+    /// The id for this company
+    pub id: i64,
+    /// The name of this company
+    pub name: String,
+    /// A description of this company
+    #[serde(default)]
+    pub description: String,
+    /// Where this company is headquartered
+    #[serde(default)]
+    pub headquarters: String,
+    /// The homepage for this company
+    #[serde(default)]
+    pub homepage: String,
+    /// The path to the logo for this company
+    pub logo_path: Option<String>,
+    /// The country of origin for this company
+    #[serde(default)]
+    pub origin_country: String,
+    /// The company that owns this company if it has one
+    pub parent_company: Option<Company>,
+}
+
+image_urls!(CompanyDetails {
+    logo_url(logo_path, LogoSize),
+});
+
+/// Another name a company or network is known by
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AlternativeName {
+    /// The alternative name
+    pub name: String,
+    /// What kind of name this is
+    #[serde(rename = "type", default)]
+    pub _type: String,
+}
+
+/// The other names a company or network is known by
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct AlternativeNames {
+    /// The id of the company or network these names are for
+    pub id: Option<i64>,
+    /// The names that were found
+    #[serde(default)]
+    pub results: Vec<AlternativeName>,
+}
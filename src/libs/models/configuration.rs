@@ -0,0 +1,220 @@
+use serde::{Deserialize, Serialize};
+
+/// The settings for building image urls
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ImageConfiguration {
+    /// The base url to get images from over http
+    pub base_url: String,
+    /// The base url to get images from over https
+    pub secure_base_url: String,
+    /// The sizes backdrops are available in
+    #[serde(default)]
+    pub backdrop_sizes: Vec<String>,
+    /// The sizes logos are available in
+    #[serde(default)]
+    pub logo_sizes: Vec<String>,
+    /// The sizes posters are available in
+    #[serde(default)]
+    pub poster_sizes: Vec<String>,
+    /// The sizes profiles are available in
+    #[serde(default)]
+    pub profile_sizes: Vec<String>,
+    /// The sizes stills are available in
+    #[serde(default)]
+    pub still_sizes: Vec<String>,
+}
+
+/// The current tmdb api configuration
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Configuration {
+    /// The settings for building image urls
+    pub images: ImageConfiguration,
+    /// The fields that can trigger a change for an item
+    #[serde(default)]
+    pub change_keys: Vec<String>,
+}
+
+impl Configuration {
+    /// Builds the https url for an image
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the image such as a `poster_path`
+    /// * `size` - The size of the image to get
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    /// use tmdb_cli::models::PosterSize;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the config to build our image urls with
+    /// let config = tmdb.configuration().await.unwrap();
+    /// // build the url to the poster for a movie
+    /// let movie = tmdb.movies.details(157336).await.unwrap();
+    /// let url = movie.poster_url(&config, PosterSize::W500);
+    /// # assert!(url.is_some())
+    /// # }
+    /// ```
+    pub fn image_url<S: ImageSize>(&self, path: &str, size: S) -> String {
+        self.sized_image_url(path, size.as_str())
+    }
+
+    /// Builds the https url for an image from a raw size
+    ///
+    /// # Arguments
+    ///
+    /// * `path` - The path to the image such as a `poster_path`
+    /// * `size` - The size of the image to get such as `w500`
+    pub(crate) fn sized_image_url(&self, path: &str, size: &str) -> String {
+        format!("{}{}{}", self.images.secure_base_url, size, path)
+    }
+}
+
+/// A size that an image can be requested in
+pub trait ImageSize: Copy {
+    /// Get the value tmdb expects for this size
+    fn as_str(self) -> &'static str;
+}
+
+/// The sizes posters can be requested in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PosterSize {
+    /// 92 pixels wide
+    W92,
+    /// 154 pixels wide
+    W154,
+    /// 185 pixels wide
+    W185,
+    /// 342 pixels wide
+    W342,
+    /// 500 pixels wide
+    W500,
+    /// 780 pixels wide
+    W780,
+    /// The size the poster was uploaded in
+    Original,
+}
+
+impl ImageSize for PosterSize {
+    fn as_str(self) -> &'static str {
+        match self {
+            PosterSize::W92 => "w92",
+            PosterSize::W154 => "w154",
+            PosterSize::W185 => "w185",
+            PosterSize::W342 => "w342",
+            PosterSize::W500 => "w500",
+            PosterSize::W780 => "w780",
+            PosterSize::Original => "original",
+        }
+    }
+}
+
+/// The sizes backdrops can be requested in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BackdropSize {
+    /// 300 pixels wide
+    W300,
+    /// 780 pixels wide
+    W780,
+    /// 1280 pixels wide
+    W1280,
+    /// The size the backdrop was uploaded in
+    Original,
+}
+
+impl ImageSize for BackdropSize {
+    fn as_str(self) -> &'static str {
+        match self {
+            BackdropSize::W300 => "w300",
+            BackdropSize::W780 => "w780",
+            BackdropSize::W1280 => "w1280",
+            BackdropSize::Original => "original",
+        }
+    }
+}
+
+/// The sizes logos can be requested in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LogoSize {
+    /// 45 pixels wide
+    W45,
+    /// 92 pixels wide
+    W92,
+    /// 154 pixels wide
+    W154,
+    /// 185 pixels wide
+    W185,
+    /// 300 pixels wide
+    W300,
+    /// 500 pixels wide
+    W500,
+    /// The size the logo was uploaded in
+    Original,
+}
+
+impl ImageSize for LogoSize {
+    fn as_str(self) -> &'static str {
+        match self {
+            LogoSize::W45 => "w45",
+            LogoSize::W92 => "w92",
+            LogoSize::W154 => "w154",
+            LogoSize::W185 => "w185",
+            LogoSize::W300 => "w300",
+            LogoSize::W500 => "w500",
+            LogoSize::Original => "original",
+        }
+    }
+}
+
+/// The sizes profiles can be requested in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ProfileSize {
+    /// 45 pixels wide
+    W45,
+    /// 185 pixels wide
+    W185,
+    /// 632 pixels tall
+    H632,
+    /// The size the profile was uploaded in
+    Original,
+}
+
+impl ImageSize for ProfileSize {
+    fn as_str(self) -> &'static str {
+        match self {
+            ProfileSize::W45 => "w45",
+            ProfileSize::W185 => "w185",
+            ProfileSize::H632 => "h632",
+            ProfileSize::Original => "original",
+        }
+    }
+}
+
+/// The sizes episode stills can be requested in
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum StillSize {
+    /// 92 pixels wide
+    W92,
+    /// 185 pixels wide
+    W185,
+    /// 300 pixels wide
+    W300,
+    /// The size the still was uploaded in
+    Original,
+}
+
+impl ImageSize for StillSize {
+    fn as_str(self) -> &'static str {
+        match self {
+            StillSize::W92 => "w92",
+            StillSize::W185 => "w185",
+            StillSize::W300 => "w300",
+            StillSize::Original => "original",
+        }
+    }
+}
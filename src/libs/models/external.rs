@@ -0,0 +1,32 @@
+use serde::{Deserialize, Serialize};
+
+/// The IDs for something in databases other than tmdb
+///
+/// Which IDs are set depends on what they are for and what tmdb knows about.
+#[derive(Serialize, Deserialize, Debug, Clone, Default)]
+pub struct ExternalIds {
+    /// The tmdb id these external ids are for
+    pub id: Option<i64>,
+    /// The imdb ID
+    pub imdb_id: Option<String>,
+    /// The freebase mid
+    pub freebase_mid: Option<String>,
+    /// The freebase ID
+    pub freebase_id: Option<String>,
+    /// The tvdb ID
+    pub tvdb_id: Option<i64>,
+    /// The tvrage ID
+    pub tvrage_id: Option<i64>,
+    /// The wikidata ID
+    pub wikidata_id: Option<String>,
+    /// The facebook ID
+    pub facebook_id: Option<String>,
+    /// The instagram ID
+    pub instagram_id: Option<String>,
+    /// The twitter ID
+    pub twitter_id: Option<String>,
+    /// The tiktok ID
+    pub tiktok_id: Option<String>,
+    /// The youtube ID
+    pub youtube_id: Option<String>,
+}
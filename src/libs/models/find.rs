@@ -0,0 +1,63 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Episode, Movie, Person, Season, Show};
+
+/// The external databases that tmdb can find items by
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ExternalSource {
+    /// An imdb ID such as `tt0816692`
+    ImdbId,
+    /// A tvdb ID
+    TvdbId,
+    /// A freebase mid
+    FreebaseMid,
+    /// A freebase ID
+    FreebaseId,
+    /// A tvrage ID
+    TvrageId,
+    /// A facebook ID
+    FacebookId,
+    /// An instagram ID
+    InstagramId,
+    /// A twitter ID
+    TwitterId,
+    /// A wikidata ID such as `Q13417189`
+    WikidataId,
+}
+
+impl ExternalSource {
+    /// Get the value tmdb expects for this source
+    pub fn as_str(self) -> &'static str {
+        match self {
+            ExternalSource::ImdbId => "imdb_id",
+            ExternalSource::TvdbId => "tvdb_id",
+            ExternalSource::FreebaseMid => "freebase_mid",
+            ExternalSource::FreebaseId => "freebase_id",
+            ExternalSource::TvrageId => "tvrage_id",
+            ExternalSource::FacebookId => "facebook_id",
+            ExternalSource::InstagramId => "instagram_id",
+            ExternalSource::TwitterId => "twitter_id",
+            ExternalSource::WikidataId => "wikidata_id",
+        }
+    }
+}
+
+/// Everything tmdb found for an external ID
+#[derive(Serialize, Deserialize, Debug)]
+pub struct FindResult {
+    /// The movies that were found
+    #[serde(default)]
+    pub movie_results: Vec<Movie>,
+    /// The shows that were found
+    #[serde(default)]
+    pub tv_results: Vec<Show>,
+    /// The people that were found
+    #[serde(default)]
+    pub person_results: Vec<Person>,
+    /// The episodes that were found
+    #[serde(default)]
+    pub tv_episode_results: Vec<Episode>,
+    /// The seasons that were found
+    #[serde(default)]
+    pub tv_season_results: Vec<Season>,
+}
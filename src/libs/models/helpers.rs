@@ -0,0 +1,56 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Deserializer};
+
+/// Deserializes an optional date that tmdb may send as an empty string
+///
+/// # Arguments
+///
+/// * `deserializer` - The deserializer to read our date from
+pub(crate) fn empty_date<'de, D>(deserializer: D) -> Result<Option<NaiveDate>, D::Error>
+where
+    D: Deserializer<'de>,
+{
+    // tmdb sends unknown dates as either null or an empty string
+    match Option::<String>::deserialize(deserializer)? {
+        Some(date) if !date.is_empty() => date.parse().map(Some).map_err(serde::de::Error::custom),
+        _ => Ok(None),
+    }
+}
+
+/// Adds methods that build the url to each image a model has a path to
+///
+/// Each method takes the size type for its kind of image so only sizes tmdb
+/// serves that image in can be requested, and returns `None` if the model has
+/// no image of that kind.
+///
+/// ```ignore
+/// image_urls!(Movie {
+///     poster_url(poster_path, PosterSize),
+///     backdrop_url(backdrop_path, BackdropSize),
+/// });
+/// ```
+macro_rules! image_urls {
+    ($model:ident { $($method:ident($path:ident, $size:ty)),+ $(,)? }) => {
+        impl $model {
+            $(
+                #[doc = concat!("Builds the url to the `", stringify!($path), "` image if there is one")]
+                ///
+                /// # Arguments
+                ///
+                /// * `config` - The tmdb configuration to build this url with
+                /// * `size` - The size of the image to get
+                pub fn $method(
+                    &self,
+                    config: &$crate::models::Configuration,
+                    size: $size,
+                ) -> Option<String> {
+                    self.$path
+                        .as_ref()
+                        .map(|path| config.image_url(path, size))
+                }
+            )+
+        }
+    };
+}
+
+pub(crate) use image_urls;
@@ -0,0 +1,55 @@
+use serde::{Deserialize, Serialize};
+
+use super::{Configuration, ImageSize};
+
+/// An image for a movie, show, season or episode
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Image {
+    /// The aspect ratio of this image
+    pub aspect_ratio: f64,
+    /// The path this image can be found at
+    pub file_path: String,
+    /// The height of this image in pixels
+    pub height: u64,
+    /// The width of this image in pixels
+    pub width: u64,
+    /// The language of any text in this image
+    pub iso_639_1: Option<String>,
+    /// The average vote for this image
+    pub vote_average: f64,
+    /// The number of votes for this image
+    pub vote_count: u64,
+}
+
+impl Image {
+    /// Builds the url to this image
+    ///
+    /// # Arguments
+    ///
+    /// * `config` - The tmdb configuration to build this url with
+    /// * `size` - The size of the image to get
+    pub fn url<S: ImageSize>(&self, config: &Configuration, size: S) -> String {
+        config.image_url(&self.file_path, size)
+    }
+}
+
+/// The images for a movie, show, season, episode, company or network
+///
+/// Only the kinds of images that exist for what was requested will be populated.
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Images {
+    /// The id of what these images are for
+    pub id: Option<i64>,
+    /// The posters for this movie, show or season
+    #[serde(default)]
+    pub posters: Vec<Image>,
+    /// The backdrops for this movie or show
+    #[serde(default)]
+    pub backdrops: Vec<Image>,
+    /// The logos for this movie, show, company or network
+    #[serde(default)]
+    pub logos: Vec<Image>,
+    /// The stills for this episode
+    #[serde(default)]
+    pub stills: Vec<Image>,
+}
@@ -0,0 +1,48 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+
+use super::{Movie, Person, Show};
+
+/// A movie, TV show or person from a list that mixes media types
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum MediaItem {
+    /// A movie
+    Movie(Movie),
+    /// A TV show
+    #[serde(rename = "tv")]
+    Show(Show),
+    /// A person
+    Person(Person),
+}
+
+impl MediaItem {
+    /// Get the title of a movie or the name of a show or person
+    pub fn title(&self) -> &str {
+        match self {
+            MediaItem::Movie(movie) => &movie.title,
+            MediaItem::Show(show) => &show.name,
+            MediaItem::Person(person) => &person.name,
+        }
+    }
+
+    /// Get the year a movie was released or a show first aired
+    ///
+    /// People and media without a known date have no year.
+    pub fn year(&self) -> Option<i32> {
+        match self {
+            MediaItem::Movie(movie) => movie.release_date.map(|date| date.year()),
+            MediaItem::Show(show) => show.first_air_date.map(|date| date.year()),
+            MediaItem::Person(_) => None,
+        }
+    }
+
+    /// Get the path to the poster for a movie or show or the profile for a person
+    pub fn poster_path(&self) -> Option<&str> {
+        match self {
+            MediaItem::Movie(movie) => movie.poster_path.as_deref(),
+            MediaItem::Show(show) => show.poster_path.as_deref(),
+            MediaItem::Person(person) => person.profile_path.as_deref(),
+        }
+    }
+}
@@ -1,15 +1,51 @@
+mod collections;
+mod companies;
+mod configuration;
+mod external;
+mod find;
 mod genre;
+mod helpers;
+mod images;
 mod language;
+mod media;
 mod movies;
 mod people;
 mod production;
+mod providers;
+mod releases;
 mod reviews;
 mod tv;
+mod videos;
 
+pub use collections::{Collection, CollectionSummary, Translation, TranslationData, Translations};
+pub use companies::{AlternativeName, AlternativeNames, Company, CompanyDetails};
+pub use configuration::{
+    BackdropSize, Configuration, ImageConfiguration, ImageSize, LogoSize, PosterSize, ProfileSize,
+    StillSize,
+};
+pub use external::ExternalIds;
+pub use find::{ExternalSource, FindResult};
 pub use genre::Genre;
+pub use images::{Image, Images};
 pub use language::Language;
-pub use movies::{Movie, MovieDetails, MovieList};
-pub use people::{Cast, Credits, Crew};
+pub use media::MediaItem;
+pub use movies::{Movie, MovieDetails, MovieDetailsBundle, MovieList};
+pub use people::{
+    Cast, CombinedCastCredit, CombinedCrewCredit, Credits, Crew, MovieCastCredit, MovieCrewCredit,
+    Person, PersonCombinedCredits, PersonDetails, PersonMovieCredits, PersonTvCredits,
+    ShowCastCredit, ShowCrewCredit,
+};
 pub use production::{ProductionCompany, ProductionCountry};
+pub use providers::{CountryCode, RegionProviders, WatchProvider, WatchProviders, WatchRegion};
+pub(crate) use providers::{WatchProviderList, WatchRegionList};
+pub(crate) use releases::CertificationList;
+pub use releases::{
+    Certification, ContentRating, ContentRatings, CountryReleaseDates, ReleaseDate, ReleaseDates,
+    ReleaseType,
+};
 pub use reviews::{Review, ReviewAuthor};
-pub use tv::{TvCreator, Episode, Network, Season, Show, ShowDetails};
+pub use tv::{
+    Episode, EpisodeCredits, EpisodeDetails, Network, NetworkDetails, Season, SeasonDetails, Show,
+    ShowDetails, ShowDetailsBundle, TvCreator,
+};
+pub use videos::{Video, VideoSite, VideoType, Videos};
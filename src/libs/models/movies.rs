@@ -1,7 +1,11 @@
 use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
-use super::{Genre, Language, ProductionCompany, ProductionCountry};
+use super::helpers::{empty_date, image_urls};
+use super::{
+    BackdropSize, CollectionSummary, Credits, ExternalIds, Genre, Images, Language, PosterSize,
+    ProductionCompany, ProductionCountry, ReleaseDates, Videos,
+};
 
 /// Details from searching for [`Movie`] by name
 #[derive(Serialize, Deserialize, Debug)]
@@ -13,7 +17,8 @@ pub struct Movie {
     /// A brief overview of this Movie
     pub overview: String,
     /// When this movie was released
-    pub release_date: NaiveDate,
+    #[serde(default, deserialize_with = "empty_date")]
+    pub release_date: Option<NaiveDate>,
     /// The genre IDs for this movie
     #[serde(default)]
     pub genre_ids: Vec<i64>,
@@ -37,6 +42,11 @@ pub struct Movie {
     pub vote_average: f64,
 }
 
+image_urls!(Movie {
+    poster_url(poster_path, PosterSize),
+    backdrop_url(backdrop_path, BackdropSize),
+});
+
 /// A cursor from a movie search
 #[derive(Serialize, Deserialize, Debug)]
 pub struct MovieList {
@@ -57,8 +67,8 @@ pub struct MovieDetails {
     pub adult: bool,
     /// The path the backdrop for this movie can be found at
     pub backdrop_path: Option<String>,
-    /// Whether this movie belongs to a collection or not
-    pub belongs_to_collection: Option<bool>,
+    /// The collection this movie belongs to if it is part of one
+    pub belongs_to_collection: Option<CollectionSummary>,
     /// The budget for this movie
     pub budget: i64,
     // The list of genres this movie is apart of
@@ -104,3 +114,26 @@ pub struct MovieDetails {
     /// The number of votes for this movie
     pub vote_count: i64,
 }
+
+image_urls!(MovieDetails {
+    poster_url(poster_path, PosterSize),
+    backdrop_url(backdrop_path, BackdropSize),
+});
+
+/// Details on a movie along with any sections appended to the same request
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MovieDetailsBundle {
+    /// The details on this movie
+    #[serde(flatten)]
+    pub details: MovieDetails,
+    /// The cast and crew for this movie if they were requested
+    pub credits: Option<Credits>,
+    /// The videos for this movie if they were requested
+    pub videos: Option<Videos>,
+    /// The images for this movie if they were requested
+    pub images: Option<Images>,
+    /// The IDs for this movie in other databases if they were requested
+    pub external_ids: Option<ExternalIds>,
+    /// The release dates for this movie if they were requested
+    pub release_dates: Option<ReleaseDates>,
+}
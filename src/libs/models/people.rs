@@ -1,5 +1,9 @@
+use chrono::prelude::*;
 use serde::{Deserialize, Serialize};
 
+use super::helpers::{empty_date, image_urls};
+use super::{MediaItem, Movie, ProfileSize, Show};
+
 /// A actor or actress for a movie or TV show
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Cast {
@@ -29,6 +33,10 @@ pub struct Cast {
     pub order: i64,
 }
 
+image_urls!(Cast {
+    profile_url(profile_path, ProfileSize),
+});
+
 /// A member of the crew for a movie or TV show
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Crew {
@@ -56,10 +64,17 @@ pub struct Crew {
     pub job: String,
 }
 
+image_urls!(Crew {
+    profile_url(profile_path, ProfileSize),
+});
+
 /// The cast and crew for a movie or TV show
 #[derive(Serialize, Deserialize, Debug)]
 pub struct Credits {
     /// The id for this particular cast/crew list
+    ///
+    /// This is 0 when credits are appended to a details request.
+    #[serde(default)]
     pub id: i64,
     /// The cast for this movie or TV show
     #[serde(default)]
@@ -68,3 +83,186 @@ pub struct Credits {
     #[serde(default)]
     pub crew: Vec<Crew>,
 }
+
+/// Details from searching for a [`Person`] by name or listing popular people
+#[derive(Serialize, Deserialize, Debug)]
+pub struct Person {
+    /// Whether this person is known for adult films
+    pub adult: bool,
+    /// The gender of this person represented as a int (0 = M, 1 = F, 2 = N/A)
+    pub gender: Option<i64>,
+    /// The ID for this person
+    pub id: i64,
+    /// The department this person is known for (acting/lighting/camera...)
+    pub known_for_department: Option<String>,
+    /// The name of this person
+    pub name: String,
+    /// The popularity of this person
+    pub popularity: f64,
+    /// The path to retrieve this persons profile at
+    pub profile_path: Option<String>,
+    /// The movies and shows this person is best known for
+    #[serde(default)]
+    pub known_for: Vec<MediaItem>,
+}
+
+image_urls!(Person {
+    profile_url(profile_path, ProfileSize),
+});
+
+/// Details on a person
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PersonDetails {
+    /// Whether this person is known for adult films
+    pub adult: bool,
+    /// Other names this person is known by
+    #[serde(default)]
+    pub also_known_as: Vec<String>,
+    /// A biography of this person
+    pub biography: String,
+    /// When this person was born
+    #[serde(default, deserialize_with = "empty_date")]
+    pub birthday: Option<NaiveDate>,
+    /// When this person died
+    #[serde(default, deserialize_with = "empty_date")]
+    pub deathday: Option<NaiveDate>,
+    /// The gender of this person represented as a int (0 = M, 1 = F, 2 = N/A)
+    pub gender: Option<i64>,
+    /// The homepage for this person
+    pub homepage: Option<String>,
+    /// The ID for this person
+    pub id: i64,
+    /// The imdb ID for this person
+    pub imdb_id: Option<String>,
+    /// The department this person is known for (acting/lighting/camera...)
+    pub known_for_department: Option<String>,
+    /// The name of this person
+    pub name: String,
+    /// Where this person was born
+    pub place_of_birth: Option<String>,
+    /// The popularity of this person
+    pub popularity: f64,
+    /// The path to retrieve this persons profile at
+    pub profile_path: Option<String>,
+}
+
+image_urls!(PersonDetails {
+    profile_url(profile_path, ProfileSize),
+});
+
+/// A role a person played in a movie
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MovieCastCredit {
+    /// The movie this role was in
+    #[serde(flatten)]
+    pub movie: Movie,
+    /// The name of the character they played
+    pub character: Option<String>,
+    /// An ID for this specific casting
+    pub credit_id: String,
+    /// Where this role is ordered in the movies cast
+    pub order: Option<i64>,
+}
+
+/// A job a person held on a movie
+#[derive(Serialize, Deserialize, Debug)]
+pub struct MovieCrewCredit {
+    /// The movie this job was on
+    #[serde(flatten)]
+    pub movie: Movie,
+    /// An ID for this specific crew credit
+    pub credit_id: String,
+    /// The department this job was in
+    pub department: String,
+    /// The job this person held
+    pub job: String,
+}
+
+/// A role a person played in a TV show
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ShowCastCredit {
+    /// The show this role was in
+    #[serde(flatten)]
+    pub show: Show,
+    /// The name of the character they played
+    pub character: Option<String>,
+    /// An ID for this specific casting
+    pub credit_id: String,
+    /// The number of episodes this person appeared in
+    pub episode_count: Option<u64>,
+}
+
+/// A job a person held on a TV show
+#[derive(Serialize, Deserialize, Debug)]
+pub struct ShowCrewCredit {
+    /// The show this job was on
+    #[serde(flatten)]
+    pub show: Show,
+    /// An ID for this specific crew credit
+    pub credit_id: String,
+    /// The department this job was in
+    pub department: String,
+    /// The job this person held
+    pub job: String,
+    /// The number of episodes this person worked on
+    pub episode_count: Option<u64>,
+}
+
+/// A role a person played in either a movie or TV show
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum CombinedCastCredit {
+    /// A role in a movie
+    Movie(MovieCastCredit),
+    /// A role in a TV show
+    Tv(ShowCastCredit),
+}
+
+/// A job a person held on either a movie or TV show
+#[derive(Serialize, Deserialize, Debug)]
+#[serde(tag = "media_type", rename_all = "lowercase")]
+pub enum CombinedCrewCredit {
+    /// A job on a movie
+    Movie(MovieCrewCredit),
+    /// A job on a TV show
+    Tv(ShowCrewCredit),
+}
+
+/// The movies a person has been in or worked on
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PersonMovieCredits {
+    /// The id of this person
+    pub id: i64,
+    /// The movies this person has acted in
+    #[serde(default)]
+    pub cast: Vec<MovieCastCredit>,
+    /// The movies this person has worked on
+    #[serde(default)]
+    pub crew: Vec<MovieCrewCredit>,
+}
+
+/// The TV shows a person has been in or worked on
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PersonTvCredits {
+    /// The id of this person
+    pub id: i64,
+    /// The shows this person has acted in
+    #[serde(default)]
+    pub cast: Vec<ShowCastCredit>,
+    /// The shows this person has worked on
+    #[serde(default)]
+    pub crew: Vec<ShowCrewCredit>,
+}
+
+/// The movies and TV shows a person has been in or worked on
+#[derive(Serialize, Deserialize, Debug)]
+pub struct PersonCombinedCredits {
+    /// The id of this person
+    pub id: i64,
+    /// The movies and shows this person has acted in
+    #[serde(default)]
+    pub cast: Vec<CombinedCastCredit>,
+    /// The movies and shows this person has worked on
+    #[serde(default)]
+    pub crew: Vec<CombinedCrewCredit>,
+}
@@ -1,5 +1,8 @@
 use serde::{Deserialize, Serialize};
 
+use super::helpers::image_urls;
+use super::LogoSize;
+
 /// A Production company for a movie or TV show
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ProductionCompany {
@@ -13,6 +16,10 @@ pub struct ProductionCompany {
     pub origin_country: String,
 }
 
+image_urls!(ProductionCompany {
+    logo_url(logo_path, LogoSize),
+});
+
 /// A country where production of a movie or TV show took place at
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ProductionCountry {
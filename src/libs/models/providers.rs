@@ -0,0 +1,88 @@
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::helpers::image_urls;
+use super::LogoSize;
+
+/// An ISO 3166-1 country code such as `US`
+pub type CountryCode = String;
+
+/// A service that movies and TV shows can be watched on
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct WatchProvider {
+    /// The id for this provider
+    pub provider_id: i64,
+    /// The name of this provider
+    pub provider_name: String,
+    /// The path to the logo for this provider
+    pub logo_path: Option<String>,
+    /// Where this provider should be listed compared to others
+    #[serde(default)]
+    pub display_priority: i64,
+    /// Where this provider should be listed in each country it is available in
+    #[serde(default)]
+    pub display_priorities: HashMap<CountryCode, i64>,
+}
+
+image_urls!(WatchProvider {
+    logo_url(logo_path, LogoSize),
+});
+
+/// The ways a movie or TV show can be watched in a single country
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct RegionProviders {
+    /// The tmdb page listing where to watch this in this country
+    pub link: Option<String>,
+    /// The subscription services this can be streamed on
+    #[serde(default)]
+    pub flatrate: Vec<WatchProvider>,
+    /// The services this can be rented from
+    #[serde(default)]
+    pub rent: Vec<WatchProvider>,
+    /// The services this can be bought from
+    #[serde(default)]
+    pub buy: Vec<WatchProvider>,
+    /// The services this can be watched on with ads
+    #[serde(default)]
+    pub ads: Vec<WatchProvider>,
+    /// The services this can be watched on for free
+    #[serde(default)]
+    pub free: Vec<WatchProvider>,
+}
+
+/// The ways a movie or TV show can be watched in each country
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct WatchProviders {
+    /// The id of what these providers are for
+    pub id: Option<i64>,
+    /// The ways this can be watched keyed by country
+    #[serde(default)]
+    pub results: HashMap<CountryCode, RegionProviders>,
+}
+
+/// A list of watch providers
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub(crate) struct WatchProviderList {
+    /// The providers that were found
+    #[serde(default)]
+    pub results: Vec<WatchProvider>,
+}
+
+/// A country that tmdb has watch providers for
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct WatchRegion {
+    /// The ISO 3166-1 code for this country
+    pub iso_3166_1: CountryCode,
+    /// The english name of this country
+    pub english_name: String,
+    /// The name of this country in its own language
+    pub native_name: Option<String>,
+}
+
+/// A list of countries that tmdb has watch providers for
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub(crate) struct WatchRegionList {
+    /// The countries that were found
+    #[serde(default)]
+    pub results: Vec<WatchRegion>,
+}
@@ -0,0 +1,221 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
+
+use super::CountryCode;
+
+/// The kinds of releases a movie can have
+#[derive(Serialize, Deserialize, Debug, Clone, Copy, PartialEq, Eq)]
+#[serde(from = "u8", into = "u8")]
+pub enum ReleaseType {
+    /// A premiere such as at a film festival
+    Premiere,
+    /// A limited theatrical release
+    TheatricalLimited,
+    /// A wide theatrical release
+    Theatrical,
+    /// A digital release such as streaming or download
+    Digital,
+    /// A physical release such as DVD or Blu-ray
+    Physical,
+    /// A release on TV
+    Tv,
+    /// A release type without a dedicated variant
+    Other(u8),
+}
+
+impl From<u8> for ReleaseType {
+    fn from(kind: u8) -> Self {
+        match kind {
+            1 => ReleaseType::Premiere,
+            2 => ReleaseType::TheatricalLimited,
+            3 => ReleaseType::Theatrical,
+            4 => ReleaseType::Digital,
+            5 => ReleaseType::Physical,
+            6 => ReleaseType::Tv,
+            _ => ReleaseType::Other(kind),
+        }
+    }
+}
+
+impl From<ReleaseType> for u8 {
+    fn from(kind: ReleaseType) -> Self {
+        match kind {
+            ReleaseType::Premiere => 1,
+            ReleaseType::TheatricalLimited => 2,
+            ReleaseType::Theatrical => 3,
+            ReleaseType::Digital => 4,
+            ReleaseType::Physical => 5,
+            ReleaseType::Tv => 6,
+            ReleaseType::Other(kind) => kind,
+        }
+    }
+}
+
+/// A single release of a movie in a country
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseDate {
+    /// The certification this release was given
+    #[serde(default)]
+    pub certification: String,
+    /// The language of this release
+    pub iso_639_1: Option<String>,
+    /// A note about this release
+    #[serde(default)]
+    pub note: String,
+    /// When this release happened
+    pub release_date: DateTime<Utc>,
+    /// What type of release this was
+    #[serde(rename = "type")]
+    pub _type: ReleaseType,
+}
+
+/// The releases of a movie in a single country
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct CountryReleaseDates {
+    /// The country these releases were in
+    pub iso_3166_1: CountryCode,
+    /// The releases in this country
+    #[serde(default)]
+    pub release_dates: Vec<ReleaseDate>,
+}
+
+impl CountryReleaseDates {
+    /// Get the certification this movie was given in this country
+    ///
+    /// Theatrical releases are preferred since other releases are often uncertified.
+    pub fn certification(&self) -> Option<&str> {
+        self.release_dates
+            .iter()
+            .filter(|release| !release.certification.is_empty())
+            .min_by_key(|release| match release._type {
+                ReleaseType::Theatrical => 0,
+                ReleaseType::TheatricalLimited => 1,
+                ReleaseType::Premiere => 2,
+                ReleaseType::Digital => 3,
+                ReleaseType::Physical => 4,
+                ReleaseType::Tv => 5,
+                ReleaseType::Other(_) => 6,
+            })
+            .map(|release| release.certification.as_str())
+    }
+}
+
+/// The releases of a movie in every country
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ReleaseDates {
+    /// The id of the movie these releases are for
+    pub id: Option<i64>,
+    /// The releases for each country
+    #[serde(default)]
+    pub results: Vec<CountryReleaseDates>,
+}
+
+impl ReleaseDates {
+    /// Get the releases of this movie in a country
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - The ISO 3166-1 code for the country such as `US`
+    pub fn country(&self, country: &str) -> Option<&CountryReleaseDates> {
+        self.results
+            .iter()
+            .find(|releases| releases.iso_3166_1 == country)
+    }
+
+    /// Get the certification this movie was given in a country
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - The ISO 3166-1 code for the country such as `US`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // check what a movie is rated in the US
+    /// let releases = tmdb.movies.release_dates(157336).await.unwrap();
+    /// assert_eq!(releases.certification("US"), Some("PG-13"));
+    /// # }
+    /// ```
+    pub fn certification(&self, country: &str) -> Option<&str> {
+        self.country(country)
+            .and_then(|releases| releases.certification())
+    }
+}
+
+/// The content rating of a TV show in a single country
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ContentRating {
+    /// The country this rating is for
+    pub iso_3166_1: CountryCode,
+    /// The rating this show was given
+    pub rating: String,
+    /// The reasons this show was given this rating
+    #[serde(default)]
+    pub descriptors: Vec<String>,
+}
+
+/// The content ratings of a TV show in every country
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct ContentRatings {
+    /// The id of the show these ratings are for
+    pub id: Option<i64>,
+    /// The ratings for each country
+    #[serde(default)]
+    pub results: Vec<ContentRating>,
+}
+
+impl ContentRatings {
+    /// Get the rating this show was given in a country
+    ///
+    /// # Arguments
+    ///
+    /// * `country` - The ISO 3166-1 code for the country such as `US`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // check what a show is rated in the US
+    /// let ratings = tmdb.tv.content_ratings(1399).await.unwrap();
+    /// assert_eq!(ratings.certification("US"), Some("TV-MA"));
+    /// # }
+    /// ```
+    pub fn certification(&self, country: &str) -> Option<&str> {
+        self.results
+            .iter()
+            .find(|rating| rating.iso_3166_1 == country && !rating.rating.is_empty())
+            .map(|rating| rating.rating.as_str())
+    }
+}
+
+/// A certification that can be given to movies or TV shows in a country
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Certification {
+    /// The name of this certification such as `PG-13`
+    pub certification: String,
+    /// What this certification means
+    #[serde(default)]
+    pub meaning: String,
+    /// Where this certification falls from least to most restrictive
+    pub order: i64,
+}
+
+/// The certifications that can be given in each country
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub(crate) struct CertificationList {
+    /// The certifications keyed by country
+    #[serde(default)]
+    pub certifications: HashMap<CountryCode, Vec<Certification>>,
+}
@@ -1,7 +1,11 @@
 use chrono::prelude::*;
-use serde::{Serialize, Deserialize};
+use serde::{Deserialize, Serialize};
 
-use super::{Genre, ProductionCompany, ProductionCountry, Language};
+use super::helpers::{empty_date, image_urls};
+use super::{
+    BackdropSize, Cast, ContentRatings, Credits, Crew, ExternalIds, Genre, Images, Language,
+    LogoSize, PosterSize, ProductionCompany, ProductionCountry, ProfileSize, StillSize, Videos,
+};
 
 /// Details from searching for [`Show`] by name
 #[derive(Serialize, Deserialize, Debug)]
@@ -19,7 +23,8 @@ pub struct Show {
     /// A brief overview of this Movie
     pub overview: String,
     /// When this show was first aired
-    pub first_air_date: NaiveDate,
+    #[serde(default, deserialize_with = "empty_date")]
+    pub first_air_date: Option<NaiveDate>,
     /// The countries this show comes from
     pub origin_country: Vec<String>,
     /// The genre IDs for this show
@@ -33,6 +38,11 @@ pub struct Show {
     pub name: String,
 }
 
+image_urls!(Show {
+    poster_url(poster_path, PosterSize),
+    backdrop_url(backdrop_path, BackdropSize),
+});
+
 /// The creator of TV show
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct TvCreator {
@@ -48,11 +58,16 @@ pub struct TvCreator {
     pub profile_path: Option<String>,
 }
 
+image_urls!(TvCreator {
+    profile_url(profile_path, ProfileSize),
+});
+
 /// An episode of a TV show
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Episode {
     /// The date this episode aired
-    pub air_date: NaiveDate,
+    #[serde(default, deserialize_with = "empty_date")]
+    pub air_date: Option<NaiveDate>,
     /// The number for this episode
     pub episode_number: u64,
     /// The id for this episode
@@ -62,9 +77,14 @@ pub struct Episode {
     /// A synopsis of this episode
     pub overview: String,
     /// The production code for identifying this episode
+    #[serde(default)]
     pub production_code: String,
+    /// How long this episode runs in minutes
+    pub runtime: Option<u64>,
     /// What season this episode is from
     pub season_number: i64,
+    /// The id of the show this episode is from if tmdb included it
+    pub show_id: Option<i64>,
     /// The path the still image for this episode can be found at
     pub still_path: Option<String>,
     /// The average vote for this episode
@@ -73,6 +93,10 @@ pub struct Episode {
     pub vote_count: i64,
 }
 
+image_urls!(Episode {
+    still_url(still_path, StillSize),
+});
+
 /// A TV network
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Network {
@@ -86,12 +110,42 @@ pub struct Network {
     pub origin_country: String,
 }
 
+image_urls!(Network {
+    logo_url(logo_path, LogoSize),
+});
+
+/// Details about a TV network
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct NetworkDetails {
+    /// The id for this network
+    pub id: i64,
+    /// The name of this network
+    pub name: String,
+    /// Where this network is headquartered
+    #[serde(default)]
+    pub headquarters: String,
+    /// The homepage for this network
+    #[serde(default)]
+    pub homepage: String,
+    /// The path this networks logo can be found at
+    pub logo_path: Option<String>,
+    /// The country this network originates from
+    #[serde(default)]
+    pub origin_country: String,
+}
+
+image_urls!(NetworkDetails {
+    logo_url(logo_path, LogoSize),
+});
+
 /// A season for a TV show
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct Season {
     /// The date this season was first aired
-    pub air_date: NaiveDate,
+    #[serde(default, deserialize_with = "empty_date")]
+    pub air_date: Option<NaiveDate>,
     /// The number of episodes in this season
+    #[serde(default)]
     pub episode_count: u64,
     /// The id for this season
     pub id: i64,
@@ -103,8 +157,14 @@ pub struct Season {
     pub poster_path: Option<String>,
     /// The number this season is
     pub season_number: i64,
+    /// The id of the show this season is from if tmdb included it
+    pub show_id: Option<i64>,
 }
 
+image_urls!(Season {
+    poster_url(poster_path, PosterSize),
+});
+
 /// Details about a TV show
 #[derive(Deserialize, Serialize, Debug, Clone)]
 pub struct ShowDetails {
@@ -132,8 +192,8 @@ pub struct ShowDetails {
     pub last_episode_to_air: Episode,
     /// The name of this show
     pub name: String,
-    /// The date the next episode airs
-    pub next_episode_to_air: Option<NaiveDate>,
+    /// The next episode to air if one is scheduled
+    pub next_episode_to_air: Option<Episode>,
     /// The networks involved with this show
     pub networks: Vec<Network>,
     /// The number of episodes in this show
@@ -166,10 +226,87 @@ pub struct ShowDetails {
     pub tagline: String,
     /// What type of show this is
     #[serde(rename = "type")]
-    pub _type: String, 
+    pub _type: String,
     /// The average vote for this show
     pub vote_average: f64,
     /// The number of votes for this show
     pub vote_count: i64,
 }
 
+image_urls!(ShowDetails {
+    poster_url(poster_path, PosterSize),
+    backdrop_url(backdrop_path, BackdropSize),
+});
+
+/// Details about a season of a TV show
+#[derive(Deserialize, Serialize, Debug, Clone)]
+pub struct SeasonDetails {
+    /// The date this season was first aired
+    #[serde(default, deserialize_with = "empty_date")]
+    pub air_date: Option<NaiveDate>,
+    /// The episodes in this season
+    #[serde(default)]
+    pub episodes: Vec<Episode>,
+    /// The id for this season
+    pub id: i64,
+    /// The name of this season
+    pub name: String,
+    /// A synopsis of this season
+    pub overview: String,
+    /// The path this seasons poster can be found at
+    pub poster_path: Option<String>,
+    /// The number this season is
+    pub season_number: i64,
+}
+
+image_urls!(SeasonDetails {
+    poster_url(poster_path, PosterSize),
+});
+
+/// Details about an episode of a TV show
+#[derive(Deserialize, Serialize, Debug)]
+pub struct EpisodeDetails {
+    /// The episode these details are for
+    #[serde(flatten)]
+    pub episode: Episode,
+    /// The crew that worked on this episode
+    #[serde(default)]
+    pub crew: Vec<Crew>,
+    /// The guest stars in this episode
+    #[serde(default)]
+    pub guest_stars: Vec<Cast>,
+}
+
+/// The cast, crew and guest stars for an episode of a TV show
+#[derive(Deserialize, Serialize, Debug)]
+pub struct EpisodeCredits {
+    /// The id for this episode
+    pub id: i64,
+    /// The regular cast in this episode
+    #[serde(default)]
+    pub cast: Vec<Cast>,
+    /// The crew that worked on this episode
+    #[serde(default)]
+    pub crew: Vec<Crew>,
+    /// The guest stars in this episode
+    #[serde(default)]
+    pub guest_stars: Vec<Cast>,
+}
+
+/// Details on a TV show along with any sections appended to the same request
+#[derive(Deserialize, Serialize, Debug)]
+pub struct ShowDetailsBundle {
+    /// The details on this show
+    #[serde(flatten)]
+    pub details: ShowDetails,
+    /// The cast and crew for this show if they were requested
+    pub credits: Option<Credits>,
+    /// The videos for this show if they were requested
+    pub videos: Option<Videos>,
+    /// The images for this show if they were requested
+    pub images: Option<Images>,
+    /// The IDs for this show in other databases if they were requested
+    pub external_ids: Option<ExternalIds>,
+    /// The content ratings for this show if they were requested
+    pub content_ratings: Option<ContentRatings>,
+}
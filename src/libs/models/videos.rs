@@ -0,0 +1,230 @@
+use chrono::prelude::*;
+use serde::{Deserialize, Serialize};
+
+/// The sites that videos can be hosted on
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum VideoSite {
+    /// The video is on YouTube
+    YouTube,
+    /// The video is on Vimeo
+    Vimeo,
+    /// The video is on a site without a dedicated variant
+    Other(String),
+}
+
+impl From<String> for VideoSite {
+    fn from(site: String) -> Self {
+        match site.as_str() {
+            "YouTube" => VideoSite::YouTube,
+            "Vimeo" => VideoSite::Vimeo,
+            _ => VideoSite::Other(site),
+        }
+    }
+}
+
+impl From<VideoSite> for String {
+    fn from(site: VideoSite) -> Self {
+        match site {
+            VideoSite::YouTube => "YouTube".into(),
+            VideoSite::Vimeo => "Vimeo".into(),
+            VideoSite::Other(site) => site,
+        }
+    }
+}
+
+/// The types of videos tmdb has
+#[derive(Serialize, Deserialize, Debug, Clone, PartialEq, Eq)]
+#[serde(from = "String", into = "String")]
+pub enum VideoType {
+    /// A trailer
+    Trailer,
+    /// A teaser
+    Teaser,
+    /// A clip
+    Clip,
+    /// A featurette
+    Featurette,
+    /// Behind the scenes footage
+    BehindTheScenes,
+    /// Bloopers
+    Bloopers,
+    /// A type without a dedicated variant
+    Other(String),
+}
+
+impl From<String> for VideoType {
+    fn from(kind: String) -> Self {
+        match kind.as_str() {
+            "Trailer" => VideoType::Trailer,
+            "Teaser" => VideoType::Teaser,
+            "Clip" => VideoType::Clip,
+            "Featurette" => VideoType::Featurette,
+            "Behind the Scenes" => VideoType::BehindTheScenes,
+            "Bloopers" => VideoType::Bloopers,
+            _ => VideoType::Other(kind),
+        }
+    }
+}
+
+impl From<VideoType> for String {
+    fn from(kind: VideoType) -> Self {
+        match kind {
+            VideoType::Trailer => "Trailer".into(),
+            VideoType::Teaser => "Teaser".into(),
+            VideoType::Clip => "Clip".into(),
+            VideoType::Featurette => "Featurette".into(),
+            VideoType::BehindTheScenes => "Behind the Scenes".into(),
+            VideoType::Bloopers => "Bloopers".into(),
+            VideoType::Other(kind) => kind,
+        }
+    }
+}
+
+/// A video such as a trailer for a movie or TV show
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Video {
+    /// The id for this video
+    pub id: String,
+    /// The language this video is in
+    pub iso_639_1: Option<String>,
+    /// The country this video is for
+    pub iso_3166_1: Option<String>,
+    /// The key to find this video with on its site
+    pub key: String,
+    /// The name of this video
+    pub name: String,
+    /// The site this video is hosted on
+    pub site: VideoSite,
+    /// The resolution of this video
+    pub size: Option<u64>,
+    /// What type of video this is
+    #[serde(rename = "type")]
+    pub _type: VideoType,
+    /// Whether this video was officially released
+    #[serde(default)]
+    pub official: bool,
+    /// When this video was published
+    pub published_at: Option<DateTime<Utc>>,
+}
+
+impl Video {
+    /// Builds the url to watch this video at if its site is known
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // print where to watch each video for a movie
+    /// for video in tmdb.movies.videos(157336).await.unwrap() {
+    ///   if let Some(url) = video.url() {
+    ///     println!("{}: {}", video.name, url);
+    ///   }
+    /// }
+    /// # }
+    /// ```
+    pub fn url(&self) -> Option<String> {
+        match self.site {
+            VideoSite::YouTube => Some(format!("https://www.youtube.com/watch?v={}", self.key)),
+            VideoSite::Vimeo => Some(format!("https://vimeo.com/{}", self.key)),
+            VideoSite::Other(_) => None,
+        }
+    }
+
+    /// Picks the best playable trailer from a list of videos
+    ///
+    /// Trailers in an earlier preferred language win over later ones and trailers in
+    /// any other language. Ties are broken by preferring official, then newer, then
+    /// higher resolution trailers.
+    ///
+    /// # Arguments
+    ///
+    /// * `videos` - The videos to pick a trailer from
+    /// * `preferred_langs` - The languages to prefer in order such as `["en"]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    /// use tmdb_cli::models::Video;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get the trailer to show for a movie
+    /// let videos = tmdb.movies.videos(157336).await.unwrap();
+    /// let trailer = Video::best_trailer(&videos, &["en"]);
+    /// # assert!(trailer.is_some())
+    /// # }
+    /// ```
+    pub fn best_trailer<'a>(videos: &'a [Video], preferred_langs: &[&str]) -> Option<&'a Video> {
+        videos
+            .iter()
+            .filter(|video| video._type == VideoType::Trailer && video.url().is_some())
+            .min_by(|a, b| {
+                // rank by language first then fall back to the newest best quality trailer
+                a.lang_rank(preferred_langs)
+                    .cmp(&b.lang_rank(preferred_langs))
+                    .then(b.official.cmp(&a.official))
+                    .then(b.published_at.cmp(&a.published_at))
+                    .then(b.size.cmp(&a.size))
+            })
+    }
+
+    /// Get where this videos language falls in a list of preferred languages
+    ///
+    /// # Arguments
+    ///
+    /// * `preferred_langs` - The languages to prefer in order
+    fn lang_rank(&self, preferred_langs: &[&str]) -> usize {
+        self.iso_639_1
+            .as_deref()
+            .and_then(|lang| preferred_langs.iter().position(|pref| *pref == lang))
+            .unwrap_or(preferred_langs.len())
+    }
+}
+
+/// The videos for a movie or TV show
+#[derive(Serialize, Deserialize, Debug, Clone)]
+pub struct Videos {
+    /// The id of what these videos are for
+    pub id: Option<i64>,
+    /// The videos that were found
+    #[serde(default)]
+    pub results: Vec<Video>,
+}
+
+impl Videos {
+    /// Picks the best playable trailer from these videos
+    ///
+    /// See [`Video::best_trailer`] for how trailers are ranked.
+    ///
+    /// # Arguments
+    ///
+    /// * `preferred_langs` - The languages to prefer in order such as `["en"]`
+    ///
+    /// # Examples
+    ///
+    /// ```
+    /// pub use tmdb_cli::Client;
+    ///
+    /// # #[tokio::main]
+    /// # async fn main() {
+    /// // build a client
+    /// let tmdb = Client::from_env();
+    /// // get details and videos for a movie in a single request
+    /// let bundle = tmdb.movies.details_with(157336).videos().exec().await.unwrap();
+    /// let trailer = bundle.videos.as_ref().and_then(|videos| videos.best_trailer(&["en"]));
+    /// # assert!(trailer.is_some())
+    /// # }
+    /// ```
+    pub fn best_trailer(&self, preferred_langs: &[&str]) -> Option<&Video> {
+        Video::best_trailer(&self.results, preferred_langs)
+    }
+}
@@ -0,0 +1,149 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use hyper::header::{HeaderName, HeaderValue, AUTHORIZATION, USER_AGENT};
+use serde_json::json;
+use std::time::Duration;
+use tmdb_cli::{Auth, Client, Error};
+
+use common::{movie, page, MockServer};
+
+/// Count how many times a query param appears in a uri
+///
+/// # Arguments
+///
+/// * `uri` - The uri to check
+/// * `key` - The param to count
+fn count_param(uri: &str, key: &str) -> usize {
+    let query = uri.split_once('?').map(|(_, query)| query).unwrap_or("");
+    query
+        .split('&')
+        .filter(|pair| pair.split('=').next() == Some(key))
+        .count()
+}
+
+/// Builds a movie search result tagged with its media type
+///
+/// # Arguments
+///
+/// * `id` - The id of this movie
+fn tagged_movie(id: u64) -> serde_json::Value {
+    let mut item = movie(id);
+    item["media_type"] = json!("movie");
+    item
+}
+
+#[tokio::test]
+async fn default_language_and_region_are_only_sent_once() {
+    let server = MockServer::start(|req| match req.uri().path() {
+        "/3/search/movie" | "/3/discover/movie" => page(req, 1, movie),
+        "/3/search/multi" => page(req, 1, tagged_movie),
+        "/3/movie/157336/images" => (200, json!({"id": 157336})),
+        _ => (
+            200,
+            json!({"images": {"base_url": "", "secure_base_url": ""}}),
+        ),
+    });
+    let tmdb: Client = server
+        .builder()
+        .language("en-US")
+        .region("US")
+        .build()
+        .unwrap();
+    // requests that set their own language and region
+    tmdb.movies
+        .search("x")
+        .language("fr-FR")
+        .region("FR".to_owned())
+        .exec()
+        .await
+        .unwrap();
+    tmdb.movies
+        .discover()
+        .language("fr-FR")
+        .region("FR")
+        .exec()
+        .await
+        .unwrap();
+    // requests that fall back to the client defaults
+    tmdb.search_multi("x").exec().await.unwrap();
+    tmdb.movies.discover().exec().await.unwrap();
+    // routes that take neither
+    tmdb.movies.images(157336).await.unwrap();
+    tmdb.configuration().await.unwrap();
+    let requests = server.requests();
+    for uri in &requests[..2] {
+        assert_eq!(count_param(uri, "language"), 1, "{}", uri);
+        assert_eq!(count_param(uri, "region"), 1, "{}", uri);
+        assert!(
+            uri.contains("language=fr-FR") && uri.contains("region=FR"),
+            "{}",
+            uri
+        );
+    }
+    // multi search only accepts a language
+    assert_eq!(count_param(&requests[2], "language"), 1);
+    assert_eq!(count_param(&requests[2], "region"), 0);
+    assert_eq!(count_param(&requests[3], "language"), 1);
+    assert_eq!(count_param(&requests[3], "region"), 1);
+    assert!(requests[3].contains("language=en-US") && requests[3].contains("region=US"));
+    for uri in &requests[4..] {
+        assert_eq!(count_param(uri, "language"), 0, "{}", uri);
+        assert_eq!(count_param(uri, "region"), 0, "{}", uri);
+    }
+}
+
+#[tokio::test]
+async fn bearer_tokens_are_sent_as_a_header() {
+    let server = MockServer::start(|_| (200, json!({"id": 157336, "results": {}})));
+    let tmdb = Client::builder(Auth::Bearer("READ_ACCESS_TOKEN".into()))
+        .host(&server.url)
+        .build()
+        .unwrap();
+    tmdb.movies.watch_providers(157336).await.unwrap();
+    let headers = &server.headers()[0];
+    assert_eq!(headers[AUTHORIZATION], "Bearer READ_ACCESS_TOKEN");
+    assert_eq!(count_param(&server.requests()[0], "api_key"), 0);
+    // api keys are sent as a query param instead
+    server
+        .client()
+        .movies
+        .watch_providers(157336)
+        .await
+        .unwrap();
+    assert!(!server.headers()[1].contains_key(AUTHORIZATION));
+    assert_eq!(count_param(&server.requests()[1], "api_key"), 1);
+}
+
+#[tokio::test(threaded_scheduler)]
+async fn builder_overrides_are_used_for_every_request() {
+    let server = MockServer::start(|req| {
+        // hang on the slow route long enough to trip our timeout
+        if req.uri().path() == "/3/movie/0/watch/providers" {
+            std::thread::sleep(Duration::from_millis(500));
+        }
+        (200, json!({"id": 157336, "results": {}}))
+    });
+    let tmdb = server
+        .builder()
+        .host(format!("{}/", server.url))
+        .timeout(Duration::from_millis(100))
+        .user_agent("tmdb-cli-tests")
+        .header(
+            HeaderName::from_static("x-test"),
+            HeaderValue::from_static("1"),
+        )
+        .build()
+        .unwrap();
+    tmdb.movies.watch_providers(157336).await.unwrap();
+    // trailing slashes are trimmed from our host
+    assert!(server.requests()[0].starts_with("/3/movie/157336/watch/providers"));
+    let headers = &server.headers()[0];
+    assert_eq!(headers[USER_AGENT], "tmdb-cli-tests");
+    assert_eq!(headers["x-test"], "1");
+    match tmdb.movies.watch_providers(0).await {
+        Err(Error::Transport(err)) => assert!(err.is_timeout()),
+        res => panic!("expected a timeout but got {:?}", res),
+    }
+}
@@ -0,0 +1,86 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+
+use common::{movie, page, query_param, MockServer};
+
+#[tokio::test]
+async fn collection_parts_are_ordered_by_release_date() {
+    let server = MockServer::start(|_| {
+        let mut sequel = movie(2);
+        sequel["release_date"] = json!("1980-05-20");
+        let mut original = movie(1);
+        original["release_date"] = json!("1977-05-25");
+        let mut upcoming = movie(3);
+        upcoming["release_date"] = json!("");
+        let body = json!({
+            "id": 10,
+            "name": "Star Wars Collection",
+            "overview": "",
+            "poster_path": null,
+            "backdrop_path": null,
+            "parts": [upcoming, sequel, original]
+        });
+        (200, body)
+    });
+    let tmdb = server.client();
+    let collection = tmdb.collections.details(10).await.unwrap();
+    assert_eq!(
+        server.requests()[0].split('?').next(),
+        Some("/3/collection/10")
+    );
+    let ids: Vec<_> = collection.parts.iter().map(|movie| movie.id).collect();
+    assert_eq!(ids, vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn collection_search_and_translations() {
+    let server = MockServer::start(|req| {
+        if req.uri().path() == "/3/search/collection" {
+            return page(req, 1, |id| {
+                json!({
+                    "id": id,
+                    "name": format!("Collection {}", id),
+                    "overview": "",
+                    "poster_path": null,
+                    "backdrop_path": null
+                })
+            });
+        }
+        let body = json!({
+            "id": 10,
+            "translations": [{
+                "iso_3166_1": "DE",
+                "iso_639_1": "de",
+                "name": "Deutsch",
+                "english_name": "German",
+                "data": {"title": "Star Wars Filmreihe", "overview": "", "homepage": ""}
+            }]
+        });
+        (200, body)
+    });
+    let tmdb = server.client();
+    let search = tmdb
+        .collections
+        .search("Star Wars")
+        .language("de")
+        .exec()
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/search/collection?"));
+    assert_eq!(query_param(uri, "query").as_deref(), Some("Star+Wars"));
+    assert_eq!(query_param(uri, "language").as_deref(), Some("de"));
+    assert!(!search.results.is_empty());
+    let translations = tmdb.collections.translations(10).await.unwrap();
+    assert_eq!(
+        server.requests()[1].split('?').next(),
+        Some("/3/collection/10/translations")
+    );
+    assert_eq!(
+        translations.translations[0].data.title,
+        "Star Wars Filmreihe"
+    );
+}
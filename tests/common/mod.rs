@@ -0,0 +1,220 @@
+#![allow(dead_code)]
+
+use hyper::service::{make_service_fn, service_fn};
+use hyper::{Body, HeaderMap, Request, Response, Server};
+use serde_json::{json, Value};
+use std::convert::Infallible;
+use std::sync::{Arc, Mutex};
+
+use tmdb_cli::{Client, ClientBuilder, RetryPolicy};
+
+/// Builds a response for a request sent to our mock server
+type Responder = dyn Fn(&Request<Body>) -> Response<Body> + Send + Sync;
+
+/// A local stand in for tmdb that records every request it receives
+pub struct MockServer {
+    /// The url this server is listening at
+    pub url: String,
+    /// The path and query of every request this server has received
+    requests: Arc<Mutex<Vec<String>>>,
+    /// The headers of every request this server has received
+    headers: Arc<Mutex<Vec<HeaderMap>>>,
+}
+
+impl MockServer {
+    /// Starts a mock server on a random local port that responds with json
+    ///
+    /// # Arguments
+    ///
+    /// * `responder` - Builds the status and json body to respond to each request with
+    pub fn start<F>(responder: F) -> Self
+    where
+        F: Fn(&Request<Body>) -> (u16, Value) + Send + Sync + 'static,
+    {
+        Self::start_with(move |req| {
+            let (status, body) = responder(req);
+            json_response(status, body)
+        })
+    }
+
+    /// Starts a mock server on a random local port with full control over each response
+    ///
+    /// # Arguments
+    ///
+    /// * `responder` - Builds the response to each request
+    pub fn start_with<F>(responder: F) -> Self
+    where
+        F: Fn(&Request<Body>) -> Response<Body> + Send + Sync + 'static,
+    {
+        let requests = Arc::new(Mutex::new(Vec::new()));
+        let headers = Arc::new(Mutex::new(Vec::new()));
+        let responder: Arc<Responder> = Arc::new(responder);
+        let (recorded, recorded_headers) = (requests.clone(), headers.clone());
+        let make_svc = make_service_fn(move |_| {
+            let recorded = recorded.clone();
+            let recorded_headers = recorded_headers.clone();
+            let responder = responder.clone();
+            async move {
+                Ok::<_, Infallible>(service_fn(move |req: Request<Body>| {
+                    // record this request so tests can check what was sent
+                    recorded.lock().unwrap().push(req.uri().to_string());
+                    recorded_headers.lock().unwrap().push(req.headers().clone());
+                    let resp = responder(&req);
+                    async move { Ok::<_, Infallible>(resp) }
+                }))
+            }
+        });
+        let server = Server::bind(&([127, 0, 0, 1], 0).into()).serve(make_svc);
+        let url = format!("http://{}", server.local_addr());
+        tokio::spawn(server);
+        MockServer {
+            url,
+            requests,
+            headers,
+        }
+    }
+
+    /// Starts building a client that talks to this server and never retries
+    pub fn builder(&self) -> ClientBuilder {
+        Client::builder("TMDB_TOKEN")
+            .host(&self.url)
+            .retry(RetryPolicy::none())
+    }
+
+    /// Builds a client that talks to this server and never retries
+    pub fn client(&self) -> Client {
+        self.builder().build().unwrap()
+    }
+
+    /// Get the path and query of every request this server has received
+    pub fn requests(&self) -> Vec<String> {
+        self.requests.lock().unwrap().clone()
+    }
+
+    /// Get the headers of every request this server has received
+    pub fn headers(&self) -> Vec<HeaderMap> {
+        self.headers.lock().unwrap().clone()
+    }
+
+    /// Get the page param of every request this server has received
+    pub fn pages(&self) -> Vec<u64> {
+        self.requests()
+            .iter()
+            .filter_map(|uri| query_param(uri, "page"))
+            .map(|page| page.parse().unwrap())
+            .collect()
+    }
+}
+
+/// Builds a json response
+///
+/// # Arguments
+///
+/// * `status` - The status code to respond with
+/// * `body` - The json body to respond with
+pub fn json_response(status: u16, body: Value) -> Response<Body> {
+    Response::builder()
+        .status(status)
+        .header("content-type", "application/json")
+        .body(Body::from(body.to_string()))
+        .unwrap()
+}
+
+/// Get a query param from a uri
+///
+/// # Arguments
+///
+/// * `uri` - The uri to get a param from
+/// * `key` - The param to get
+pub fn query_param(uri: &str, key: &str) -> Option<String> {
+    let (_, query) = uri.split_once('?')?;
+    query
+        .split('&')
+        .filter_map(|pair| pair.split_once('='))
+        .find(|(name, _)| *name == key)
+        .map(|(_, value)| value.to_owned())
+}
+
+/// Builds a movie for a mock response
+///
+/// # Arguments
+///
+/// * `id` - The id of this movie
+pub fn movie(id: u64) -> Value {
+    json!({
+        "poster_path": null,
+        "adult": false,
+        "overview": "",
+        "release_date": "2016-01-15",
+        "genre_ids": [],
+        "id": id,
+        "original_title": format!("Movie {}", id),
+        "title": format!("Movie {}", id),
+        "backdrop_path": null,
+        "popularity": 1.0,
+        "vote_count": 1,
+        "video": false,
+        "vote_average": 1.0
+    })
+}
+
+/// Builds a show for a mock response
+///
+/// # Arguments
+///
+/// * `id` - The id of this show
+pub fn show(id: u64) -> Value {
+    json!({
+        "poster_path": null,
+        "popularity": 1.0,
+        "id": id,
+        "backdrop_path": null,
+        "vote_average": 1.0,
+        "overview": "",
+        "first_air_date": "2003-04-01",
+        "origin_country": ["US"],
+        "genre_ids": [],
+        "vote_count": 1,
+        "name": format!("Show {}", id)
+    })
+}
+
+/// Builds a person for a mock response
+///
+/// # Arguments
+///
+/// * `id` - The id of this person
+pub fn person(id: u64) -> Value {
+    json!({
+        "adult": false,
+        "gender": 2,
+        "id": id,
+        "known_for_department": "Acting",
+        "name": format!("Person {}", id),
+        "popularity": 1.0,
+        "profile_path": null
+    })
+}
+
+/// Builds a page of results for whatever page was requested
+///
+/// Each page has 2 results whose ids are based on their page.
+///
+/// # Arguments
+///
+/// * `req` - The request to respond to
+/// * `total_pages` - The total number of pages that exist
+/// * `item` - Builds an item for this page from its id
+pub fn page(req: &Request<Body>, total_pages: u64, item: fn(u64) -> Value) -> (u16, Value) {
+    let page: u64 = query_param(&req.uri().to_string(), "page")
+        .map(|page| page.parse().unwrap())
+        .unwrap_or(1);
+    let results: Vec<Value> = (0..2).map(|i| item(page * 100 + i)).collect();
+    let body = json!({
+        "page": page,
+        "results": results,
+        "total_pages": total_pages,
+        "total_results": total_pages * 2,
+    });
+    (200, body)
+}
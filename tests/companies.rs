@@ -0,0 +1,137 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+use tmdb_cli::MovieSort;
+
+use common::{movie, page, query_param, show, MockServer};
+
+#[tokio::test]
+async fn company_routes_and_search() {
+    let server = MockServer::start(|req| {
+        let path = req.uri().path().to_owned();
+        if path == "/3/search/company" {
+            return page(
+                req,
+                1,
+                |id| json!({"id": id, "logo_path": null, "name": format!("Company {}", id), "origin_country": "US"}),
+            );
+        }
+        let body = match path.as_str() {
+            "/3/company/3" => json!({
+                "description": "",
+                "headquarters": "Emeryville, California, United States",
+                "homepage": "https://www.pixar.com",
+                "id": 3,
+                "logo_path": "/pixar.png",
+                "name": "Pixar",
+                "origin_country": "US",
+                "parent_company": {"id": 2, "logo_path": null, "name": "Walt Disney Pictures"}
+            }),
+            "/3/company/3/alternative_names" => json!({
+                "id": 3,
+                "results": [{"name": "Pixar Animation Studios", "type": ""}]
+            }),
+            _ => json!({
+                "id": 3,
+                "logos": [{
+                    "aspect_ratio": 1.0,
+                    "file_path": "/pixar.png",
+                    "height": 100,
+                    "width": 100,
+                    "iso_639_1": null,
+                    "vote_average": 0.0,
+                    "vote_count": 0
+                }]
+            }),
+        };
+        (200, body)
+    });
+    let tmdb = server.client();
+    let search = tmdb.companies.search("Pixar").exec().await.unwrap();
+    let details = tmdb.companies.details(3).await.unwrap();
+    let names = tmdb.companies.alternative_names(3).await.unwrap();
+    let images = tmdb.companies.images(3).await.unwrap();
+    assert_eq!(
+        query_param(&server.requests()[0], "query").as_deref(),
+        Some("Pixar")
+    );
+    assert_eq!(search.results[0].origin_country.as_deref(), Some("US"));
+    assert_eq!(details.parent_company.unwrap().name, "Walt Disney Pictures");
+    assert_eq!(names.results[0].name, "Pixar Animation Studios");
+    assert_eq!(images.logos[0].file_path, "/pixar.png");
+    let paths: Vec<_> = server
+        .requests()
+        .iter()
+        .map(|uri| uri.split('?').next().unwrap().to_owned())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/3/search/company",
+            "/3/company/3",
+            "/3/company/3/alternative_names",
+            "/3/company/3/images"
+        ]
+    );
+}
+
+#[tokio::test]
+async fn companies_and_networks_discover_their_catalog() {
+    let server = MockServer::start(|req| {
+        let path = req.uri().path().to_owned();
+        match path.as_str() {
+            "/3/discover/movie" => page(req, 1, movie),
+            "/3/discover/tv" => page(req, 1, show),
+            "/3/network/49" => (
+                200,
+                json!({
+                    "headquarters": "New York City, New York",
+                    "homepage": "https://www.hbo.com",
+                    "id": 49,
+                    "logo_path": "/hbo.png",
+                    "name": "HBO",
+                    "origin_country": "US"
+                }),
+            ),
+            _ => (
+                200,
+                json!({"id": 49, "results": [{"name": "Home Box Office", "type": ""}]}),
+            ),
+        }
+    });
+    let tmdb = server.client();
+    tmdb.companies
+        .movies(3)
+        .sort_by(MovieSort::PopularityDesc)
+        .exec()
+        .await
+        .unwrap();
+    tmdb.companies.shows(3).exec().await.unwrap();
+    tmdb.networks.shows(49).exec().await.unwrap();
+    let network = tmdb.networks.details(49).await.unwrap();
+    let names = tmdb.networks.alternative_names(49).await.unwrap();
+    let requests = server.requests();
+    assert!(requests[0].starts_with("/3/discover/movie?"));
+    assert_eq!(
+        query_param(&requests[0], "with_companies").as_deref(),
+        Some("3")
+    );
+    assert_eq!(
+        query_param(&requests[0], "sort_by").as_deref(),
+        Some("popularity.desc")
+    );
+    assert!(requests[1].starts_with("/3/discover/tv?"));
+    assert_eq!(
+        query_param(&requests[1], "with_companies").as_deref(),
+        Some("3")
+    );
+    assert_eq!(
+        query_param(&requests[2], "with_networks").as_deref(),
+        Some("49")
+    );
+    assert_eq!(network.name, "HBO");
+    assert!(requests[4].starts_with("/3/network/49/alternative_names?"));
+    assert_eq!(names.results[0].name, "Home Box Office");
+}
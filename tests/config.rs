@@ -0,0 +1,124 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use hyper::header::AUTHORIZATION;
+use std::env;
+use std::path::PathBuf;
+use std::sync::{Mutex, MutexGuard};
+use tmdb_cli::{Client, ClientConfig, Error};
+
+use common::{movie, page, query_param, MockServer};
+
+/// Every environment variable a config can be overridden with
+const VARS: [&str; 8] = [
+    "TMDB_TOKEN",
+    "TMDB_READ_ACCESS_TOKEN",
+    "TMDB_HOST",
+    "TMDB_LANGUAGE",
+    "TMDB_REGION",
+    "TMDB_TIMEOUT",
+    "TMDB_CACHE_DIR",
+    "TMDB_CACHE_TTL",
+];
+
+/// Tests in this file share one environment so only let one touch it at a time
+static ENV: Mutex<()> = Mutex::new(());
+
+/// Locks the environment and clears any tmdb settings from it
+fn clean_env() -> MutexGuard<'static, ()> {
+    let guard = ENV.lock().unwrap_or_else(|e| e.into_inner());
+    for var in VARS.iter() {
+        env::remove_var(var);
+    }
+    guard
+}
+
+/// Writes a config file to a scratch path
+///
+/// # Arguments
+///
+/// * `name` - The name of the file to write
+/// * `data` - The contents of this file
+fn write_config(name: &str, data: &str) -> PathBuf {
+    let path = env::temp_dir().join(format!("tmdb-cli-{}-{}", std::process::id(), name));
+    std::fs::write(&path, data).unwrap();
+    path
+}
+
+#[test]
+fn config_files_are_parsed_by_extension() {
+    let toml = write_config(
+        "config.toml",
+        r#"
+token = "FILE_TOKEN"
+language = "en-US"
+region = "US"
+timeout = 10
+
+[cache]
+dir = "/var/cache/tmdb"
+ttl = 86400
+"#,
+    );
+    let config = ClientConfig::from_file(&toml).unwrap();
+    assert_eq!(config.token.as_deref(), Some("FILE_TOKEN"));
+    assert_eq!(config.language.as_deref(), Some("en-US"));
+    assert_eq!(config.region.as_deref(), Some("US"));
+    assert_eq!(config.timeout, Some(10));
+    assert_eq!(config.cache.dir, Some(PathBuf::from("/var/cache/tmdb")));
+    assert_eq!(config.cache.ttl, Some(86400));
+    let json = write_config("config.json", r#"{"read_access_token": "FILE_TOKEN"}"#);
+    let config = ClientConfig::from_file(&json).unwrap();
+    assert_eq!(config.read_access_token.as_deref(), Some("FILE_TOKEN"));
+    assert!(config.token.is_none());
+    // json isn't valid toml so files are only parsed as json by their extension
+    let wrong = write_config("config.conf", r#"{"token": "FILE_TOKEN"}"#);
+    assert!(matches!(
+        ClientConfig::from_file(&wrong),
+        Err(Error::Config(_))
+    ));
+}
+
+#[tokio::test]
+async fn environment_overrides_config_files() {
+    let server = MockServer::start(|req| page(req, 1, movie));
+    let path = write_config(
+        "override.toml",
+        &format!(
+            "token = \"FILE_TOKEN\"\nhost = \"{}\"\nlanguage = \"en-US\"\nregion = \"US\"\n",
+            server.url
+        ),
+    );
+    let tmdb = {
+        let _env = clean_env();
+        // a token from the environment replaces both tokens from the file
+        env::set_var("TMDB_READ_ACCESS_TOKEN", "ENV_TOKEN");
+        env::set_var("TMDB_LANGUAGE", "de-DE");
+        // empty variables are treated as unset
+        env::set_var("TMDB_REGION", "");
+        Client::from_file(&path).unwrap()
+    };
+    tmdb.movies.popular().exec().await.unwrap();
+    let uri = &server.requests()[0];
+    assert_eq!(server.headers()[0][AUTHORIZATION], "Bearer ENV_TOKEN");
+    assert_eq!(query_param(uri, "api_key"), None);
+    assert_eq!(query_param(uri, "language").as_deref(), Some("de-DE"));
+    assert_eq!(query_param(uri, "region").as_deref(), Some("US"));
+}
+
+#[test]
+fn missing_tokens_are_a_config_error() {
+    let _env = clean_env();
+    env::set_var("TMDB_TOKEN", "");
+    env::set_var("TMDB_LANGUAGE", "en-US");
+    match Client::try_from_env() {
+        Err(Error::Config(msg)) => assert!(msg.contains("TMDB_TOKEN")),
+        Err(err) => panic!("expected a config error but got {}", err),
+        Ok(_) => panic!("built a client without a token"),
+    }
+    // bad numbers are reported instead of ignored
+    env::set_var("TMDB_TOKEN", "ENV_TOKEN");
+    env::set_var("TMDB_TIMEOUT", "soon");
+    assert!(matches!(Client::try_from_env(), Err(Error::Config(_))));
+}
@@ -0,0 +1,67 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+use tmdb_cli::models::{BackdropSize, PosterSize};
+use tmdb_cli::CacheConfig;
+
+use common::{movie, MockServer};
+
+/// Builds a configuration response
+fn configuration() -> (u16, serde_json::Value) {
+    let body = json!({
+        "images": {
+            "base_url": "http://image.tmdb.org/t/p/",
+            "secure_base_url": "https://image.tmdb.org/t/p/",
+            "backdrop_sizes": ["w300", "w780", "w1280", "original"],
+            "logo_sizes": ["w45", "original"],
+            "poster_sizes": ["w92", "w500", "original"],
+            "profile_sizes": ["w45", "h632", "original"],
+            "still_sizes": ["w92", "original"]
+        },
+        "change_keys": ["title"]
+    });
+    (200, body)
+}
+
+#[tokio::test]
+async fn configuration_is_cached_on_the_client() {
+    let server = MockServer::start(|_| configuration());
+    let tmdb = server.client();
+    let first = tmdb.configuration().await.unwrap();
+    let second = tmdb.configuration().await.unwrap();
+    assert_eq!(server.requests().len(), 1);
+    assert!(server.requests()[0].starts_with("/3/configuration?"));
+    assert_eq!(first.images.secure_base_url, second.images.secure_base_url);
+}
+
+#[tokio::test]
+async fn configuration_is_refetched_once_expired() {
+    let server = MockServer::start(|_| configuration());
+    let cache = CacheConfig {
+        dir: None,
+        ttl: Some(0),
+    };
+    let tmdb = server.builder().cache(cache).build().unwrap();
+    tmdb.configuration().await.unwrap();
+    tmdb.configuration().await.unwrap();
+    assert_eq!(server.requests().len(), 2);
+}
+
+#[tokio::test]
+async fn image_urls_use_the_secure_base_url() {
+    let server = MockServer::start(|_| configuration());
+    let tmdb = server.client();
+    let config = tmdb.configuration().await.unwrap();
+    let mut with_poster = movie(1);
+    with_poster["poster_path"] = json!("/poster.jpg");
+    let with_poster: tmdb_cli::models::Movie = serde_json::from_value(with_poster).unwrap();
+    assert_eq!(
+        with_poster.poster_url(&config, PosterSize::W500).as_deref(),
+        Some("https://image.tmdb.org/t/p/w500/poster.jpg")
+    );
+    assert!(with_poster
+        .backdrop_url(&config, BackdropSize::Original)
+        .is_none());
+}
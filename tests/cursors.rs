@@ -0,0 +1,260 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use futures::StreamExt;
+use serde_json::json;
+use tmdb_cli::Error;
+
+use common::{movie, page, query_param, show, MockServer};
+
+#[tokio::test]
+async fn movie_search_requests_first_page() {
+    let server = MockServer::start(|req| page(req, 3, movie));
+    let tmdb = server.client();
+    let cursor = tmdb.movies.search("13 Hours").exec().await.unwrap();
+    assert_eq!(server.pages(), vec![1]);
+    assert_eq!(cursor.page, 1);
+    assert_eq!(cursor.results[0].id, 100);
+}
+
+#[tokio::test]
+async fn movie_search_requests_set_page() {
+    let server = MockServer::start(|req| page(req, 10, movie));
+    let tmdb = server.client();
+    let cursor = tmdb.movies.search("13 Hours").page(5).exec().await.unwrap();
+    assert_eq!(server.pages(), vec![5]);
+    assert_eq!(cursor.page, 5);
+}
+
+#[tokio::test]
+async fn show_search_requests_set_page() {
+    let server = MockServer::start(|req| page(req, 10, show));
+    let tmdb = server.client();
+    let first = tmdb.tv.search("Red Vs. Blue").exec().await.unwrap();
+    let fifth = tmdb.tv.search("Red Vs. Blue").page(5).exec().await.unwrap();
+    assert_eq!(server.pages(), vec![1, 5]);
+    assert_eq!(first.page, 1);
+    assert_eq!(fifth.page, 5);
+}
+
+#[tokio::test]
+async fn search_sends_query_params() {
+    let server = MockServer::start(|req| page(req, 1, movie));
+    let tmdb = server.client();
+    tmdb.movies
+        .search("13 Hours")
+        .year(2016)
+        .exec()
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/search/movie?"));
+    assert_eq!(query_param(uri, "year").as_deref(), Some("2016"));
+    assert_eq!(query_param(uri, "api_key").as_deref(), Some("TMDB_TOKEN"));
+}
+
+#[tokio::test]
+async fn next_page_requests_following_page() {
+    let server = MockServer::start(|req| page(req, 3, movie));
+    let tmdb = server.client();
+    let cursor = tmdb.movies.popular().exec().await.unwrap();
+    assert!(cursor.has_next());
+    assert!(!cursor.is_last());
+    let cursor = cursor.next_page().await.unwrap();
+    assert_eq!(cursor.page, 2);
+    assert_eq!(cursor.results[0].id, 200);
+    assert_eq!(server.pages(), vec![1, 2]);
+}
+
+#[tokio::test]
+async fn next_page_loads_the_set_page_of_an_unloaded_cursor() {
+    let server = MockServer::start(|req| page(req, 10, movie));
+    let tmdb = server.client();
+    let first = tmdb.movies.popular().next_page().await.unwrap();
+    assert_eq!(first.page, 1);
+    let fifth = tmdb.movies.popular().page(5).next_page().await.unwrap();
+    assert_eq!(fifth.page, 5);
+    assert_eq!(fifth.results[0].id, 500);
+    // once loaded the cursor moves on to the following page
+    let sixth = fifth.next_page().await.unwrap();
+    assert_eq!(sixth.page, 6);
+    assert_eq!(server.pages(), vec![1, 5, 6]);
+}
+
+#[tokio::test]
+async fn next_page_fails_on_last_page() {
+    let server = MockServer::start(|req| page(req, 2, movie));
+    let tmdb = server.client();
+    let cursor = tmdb.movies.popular().page(2).exec().await.unwrap();
+    assert!(cursor.is_last());
+    assert!(!cursor.has_next());
+    match cursor.next_page().await {
+        Err(Error::PageOutOfRange { page, total_pages }) => {
+            assert_eq!(page, 3);
+            assert_eq!(total_pages, 2);
+        }
+        other => panic!("expected PageOutOfRange got {:?}", other.map(|c| c.page)),
+    }
+    // no request should be sent for a page that does not exist
+    assert_eq!(server.pages(), vec![2]);
+}
+
+#[tokio::test]
+async fn prev_page_requests_previous_page() {
+    let server = MockServer::start(|req| page(req, 3, movie));
+    let tmdb = server.client();
+    let cursor = tmdb.movies.popular().page(3).exec().await.unwrap();
+    let cursor = cursor.prev_page().await.unwrap();
+    assert_eq!(cursor.page, 2);
+    let cursor = cursor.prev_page().await.unwrap();
+    assert_eq!(cursor.page, 1);
+    assert!(matches!(
+        cursor.prev_page().await,
+        Err(Error::PageOutOfRange { page: 0, .. })
+    ));
+    assert_eq!(server.pages(), vec![3, 2, 1]);
+}
+
+#[tokio::test]
+async fn goto_page_is_bounds_checked() {
+    let server = MockServer::start(|req| page(req, 4, movie));
+    let tmdb = server.client();
+    let cursor = tmdb.movies.popular().goto_page(2).await.unwrap();
+    assert_eq!(cursor.page, 2);
+    let cursor = cursor.goto_page(4).await.unwrap();
+    assert_eq!(cursor.page, 4);
+    assert!(cursor.is_last());
+    assert!(matches!(
+        cursor.goto_page(5).await,
+        Err(Error::PageOutOfRange {
+            page: 5,
+            total_pages: 4
+        })
+    ));
+    assert!(matches!(
+        tmdb.movies.popular().goto_page(0).await,
+        Err(Error::PageOutOfRange { page: 0, .. })
+    ));
+    assert_eq!(server.pages(), vec![2, 4]);
+}
+
+#[tokio::test]
+async fn stream_walks_every_page_once() {
+    let server = MockServer::start(|req| page(req, 3, movie));
+    let tmdb = server.client();
+    let ids: Vec<i64> = tmdb
+        .movies
+        .popular()
+        .into_stream()
+        .map(|movie| movie.unwrap().id)
+        .collect()
+        .await;
+    assert_eq!(ids, vec![100, 101, 200, 201, 300, 301]);
+    assert_eq!(server.pages(), vec![1, 2, 3]);
+}
+
+#[tokio::test]
+async fn stream_continues_from_loaded_page() {
+    let server = MockServer::start(|req| page(req, 3, movie));
+    let tmdb = server.client();
+    let cursor = tmdb.movies.popular().page(2).exec().await.unwrap();
+    let ids: Vec<i64> = cursor
+        .into_stream()
+        .map(|movie| movie.unwrap().id)
+        .collect()
+        .await;
+    assert_eq!(ids, vec![200, 201, 300, 301]);
+    assert_eq!(server.pages(), vec![2, 3]);
+}
+
+#[tokio::test]
+async fn stream_stops_at_limits() {
+    let server = MockServer::start(|req| page(req, 10, movie));
+    let tmdb = server.client();
+    let items = tmdb.movies.popular().max_items(3).into_stream();
+    assert_eq!(items.collect::<Vec<_>>().await.len(), 3);
+    assert_eq!(server.pages(), vec![1, 2]);
+    let pages = tmdb.movies.popular().max_pages(2).into_stream();
+    assert_eq!(pages.collect::<Vec<_>>().await.len(), 4);
+    assert_eq!(server.pages(), vec![1, 2, 1, 2]);
+}
+
+#[tokio::test]
+async fn stream_stops_at_the_last_page_tmdb_returns() {
+    let server = MockServer::start(|req| {
+        // tmdb rejects pages past 500 no matter how many pages exist
+        let uri = req.uri().to_string();
+        match query_param(&uri, "page").map(|num| num.parse::<u64>().unwrap()) {
+            Some(num) if num > 500 => (422, json!({"status_message": "Invalid page"})),
+            _ => page(req, 1000, movie),
+        }
+    });
+    let tmdb = server.client();
+    let items: Vec<_> = tmdb
+        .movies
+        .popular()
+        .page(499)
+        .into_stream()
+        .collect()
+        .await;
+    assert_eq!(items.len(), 4);
+    assert!(items.iter().all(|item| item.is_ok()));
+    assert_eq!(server.pages(), vec![499, 500]);
+    // navigating past page 500 should fail without asking tmdb
+    let cursor = tmdb.movies.popular().page(500).exec().await.unwrap();
+    assert!(cursor.is_last());
+    assert!(!cursor.has_next());
+    assert!(matches!(
+        cursor.next_page().await,
+        Err(Error::PageOutOfRange { page: 501, .. })
+    ));
+    assert_eq!(server.pages(), vec![499, 500, 500]);
+}
+
+#[tokio::test]
+async fn search_stream_starts_at_set_page() {
+    let server = MockServer::start(|req| page(req, 3, movie));
+    let tmdb = server.client();
+    let items = tmdb.movies.search("13 Hours").page(2).into_stream();
+    assert_eq!(items.collect::<Vec<_>>().await.len(), 4);
+    assert_eq!(server.pages(), vec![2, 3]);
+}
+
+#[tokio::test]
+async fn collect_all_returns_pages_in_order() {
+    let server = MockServer::start(|req| page(req, 5, movie));
+    let tmdb = server.client();
+    let ids: Vec<i64> = tmdb
+        .movies
+        .popular()
+        .collect_all(3)
+        .await
+        .unwrap()
+        .iter()
+        .map(|movie| movie.id)
+        .collect();
+    let expected: Vec<i64> = (1..=5).flat_map(|p| vec![p * 100, p * 100 + 1]).collect();
+    assert_eq!(ids, expected);
+    let mut pages = server.pages();
+    pages.sort();
+    assert_eq!(pages, vec![1, 2, 3, 4, 5]);
+}
+
+#[tokio::test]
+async fn fetch_pages_returns_requested_pages() {
+    let server = MockServer::start(|req| page(req, 5, movie));
+    let tmdb = server.client();
+    let cursor = tmdb.movies.popular();
+    let ids: Vec<i64> = cursor
+        .fetch_pages(vec![4, 2], 2)
+        .await
+        .unwrap()
+        .iter()
+        .map(|movie| movie.id)
+        .collect();
+    assert_eq!(ids, vec![400, 401, 200, 201]);
+    // fetching pages should not change what the cursor has loaded
+    assert_eq!(cursor.page, 1);
+    assert!(cursor.results.is_empty());
+}
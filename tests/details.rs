@@ -0,0 +1,83 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+
+use common::{query_param, MockServer};
+
+#[tokio::test]
+async fn details_with_appends_sections_to_one_request() {
+    let server = MockServer::start(|req| {
+        let uri = req.uri().to_string();
+        let mut body = json!({
+            "adult": false,
+            "backdrop_path": null,
+            "belongs_to_collection": {
+                "id": 1,
+                "name": "Interstellar Collection",
+                "poster_path": null,
+                "backdrop_path": null
+            },
+            "budget": 1,
+            "genres": [],
+            "homepage": null,
+            "id": 157336,
+            "imdb_id": "tt0816692",
+            "original_language": "en",
+            "original_title": "Interstellar",
+            "overview": null,
+            "popularity": 1.0,
+            "poster_path": null,
+            "production_companies": [],
+            "production_countries": [],
+            "release_date": "2014-11-05",
+            "revenue": 1,
+            "runtime": 169,
+            "spoken_languages": [],
+            "status": "Released",
+            "tagline": null,
+            "title": "Interstellar",
+            "video": false,
+            "vote_average": 1.0,
+            "vote_count": 1
+        });
+        // only include the sections that were asked for
+        let sections = query_param(&uri, "append_to_response").unwrap_or_default();
+        if sections.contains("credits") {
+            body["credits"] = json!({"cast": [], "crew": []});
+        }
+        if sections.contains("external_ids") {
+            body["external_ids"] = json!({"imdb_id": "tt0816692", "wikidata_id": "Q13417189"});
+        }
+        (200, body)
+    });
+    let tmdb = server.client();
+    let bundle = tmdb
+        .movies
+        .details_with(157336)
+        .credits()
+        .external_ids()
+        .credits()
+        .exec()
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/movie/157336?"));
+    assert_eq!(
+        query_param(uri, "append_to_response").as_deref(),
+        Some("credits%2Cexternal_ids")
+    );
+    assert_eq!(server.requests().len(), 1);
+    assert_eq!(bundle.details.title, "Interstellar");
+    assert_eq!(
+        bundle.details.belongs_to_collection.unwrap().name,
+        "Interstellar Collection"
+    );
+    assert!(bundle.credits.is_some());
+    assert_eq!(
+        bundle.external_ids.unwrap().wikidata_id.as_deref(),
+        Some("Q13417189")
+    );
+    assert!(bundle.videos.is_none());
+}
@@ -0,0 +1,70 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use chrono::NaiveDate;
+use tmdb_cli::{Join, MovieSort, ShowSort};
+
+use common::{movie, page, query_param, show, MockServer};
+
+#[tokio::test]
+async fn movie_discover_sends_typed_filters() {
+    let server = MockServer::start(|req| page(req, 1, movie));
+    let tmdb = server.client();
+    let cursor = tmdb
+        .movies
+        .discover()
+        .sort_by(MovieSort::VoteAverageDesc)
+        .genres(vec![28, 12], Join::Or)
+        .without_genres(vec![27], Join::And)
+        .release_date_gte(NaiveDate::from_ymd_opt(2010, 1, 1).unwrap())
+        .vote_count_gte(100)
+        .with_cast(vec![1, 2], Join::And)
+        .certification("US", "R")
+        .exec()
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/discover/movie?"));
+    assert_eq!(
+        query_param(uri, "sort_by").as_deref(),
+        Some("vote_average.desc")
+    );
+    assert_eq!(query_param(uri, "with_genres").as_deref(), Some("28%7C12"));
+    assert_eq!(query_param(uri, "without_genres").as_deref(), Some("27"));
+    assert_eq!(
+        query_param(uri, "release_date.gte").as_deref(),
+        Some("2010-01-01")
+    );
+    assert_eq!(query_param(uri, "vote_count.gte").as_deref(), Some("100"));
+    assert_eq!(query_param(uri, "with_cast").as_deref(), Some("1%2C2"));
+    assert_eq!(
+        query_param(uri, "certification_country").as_deref(),
+        Some("US")
+    );
+    assert_eq!(cursor.results[0].id, 100);
+}
+
+#[tokio::test]
+async fn show_discover_replaces_repeated_filters() {
+    let server = MockServer::start(|req| page(req, 3, show));
+    let tmdb = server.client();
+    tmdb.tv
+        .discover()
+        .sort_by(ShowSort::PopularityAsc)
+        .sort_by(ShowSort::FirstAirDateDesc)
+        .with_networks(vec![213], Join::And)
+        .page(2)
+        .exec()
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/discover/tv?"));
+    assert_eq!(uri.matches("sort_by=").count(), 1);
+    assert_eq!(
+        query_param(uri, "sort_by").as_deref(),
+        Some("first_air_date.desc")
+    );
+    assert_eq!(query_param(uri, "with_networks").as_deref(), Some("213"));
+    assert_eq!(server.pages(), vec![2]);
+}
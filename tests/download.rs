@@ -0,0 +1,290 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use hyper::{Body, Request, Response};
+use serde_json::json;
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::{Arc, Mutex};
+use std::time::Duration;
+use tmdb_cli::models::{BackdropSize, PosterSize};
+use tmdb_cli::{CacheConfig, Client, ImageDownload, RateLimiter};
+
+use common::{json_response, MockServer};
+
+/// Builds a configuration pointing image urls back at the server a request was sent to
+///
+/// # Arguments
+///
+/// * `req` - The request for the configuration
+fn configuration(req: &Request<Body>) -> serde_json::Value {
+    let host = req.headers()["host"].to_str().unwrap();
+    json!({
+        "images": {
+            "base_url": format!("http://{}/t/p/", host),
+            "secure_base_url": format!("http://{}/t/p/", host),
+        }
+    })
+}
+
+/// Serves a configuration pointing back at this server and an image for everything else
+fn respond(req: &Request<Body>) -> (u16, serde_json::Value) {
+    if req.uri().path() == "/3/configuration" {
+        return (200, configuration(req));
+    }
+    (200, json!(req.uri().path()))
+}
+
+/// The image a versioned mock server is currently serving
+struct Version {
+    /// The etag of the current image
+    etag: &'static str,
+    /// The contents of the current image
+    body: &'static str,
+}
+
+/// Starts a server that serves a single image by etag
+///
+/// # Arguments
+///
+/// * `version` - The image to serve which tests can change
+/// * `not_modified` - Counts the 304s this server sent
+fn versioned(version: Arc<Mutex<Version>>, not_modified: Arc<AtomicUsize>) -> MockServer {
+    MockServer::start_with(move |req| {
+        if req.uri().path() == "/3/configuration" {
+            return json_response(200, configuration(req));
+        }
+        let version = version.lock().unwrap();
+        // tell clients with the current etag their copy is still good
+        let sent = req.headers().get("if-none-match");
+        if sent.map(|etag| etag == version.etag).unwrap_or(false) {
+            not_modified.fetch_add(1, Ordering::SeqCst);
+            return Response::builder().status(304).body(Body::empty()).unwrap();
+        }
+        Response::builder()
+            .status(200)
+            .header("etag", version.etag)
+            .body(Body::from(version.body))
+            .unwrap()
+    })
+}
+
+/// Builds an empty directory to download images into
+///
+/// # Arguments
+///
+/// * `name` - The name of the test using this directory
+fn scratch(name: &str) -> PathBuf {
+    let dir = std::env::temp_dir().join(format!("tmdb-cli-{}-{}", name, std::process::id()));
+    let _ = std::fs::remove_dir_all(&dir);
+    dir
+}
+
+/// Builds a client that keeps its etag index in a cache directory
+///
+/// # Arguments
+///
+/// * `server` - The server to talk to
+/// * `dir` - The directory to use as our cache
+fn cached_client(server: &MockServer, dir: &Path) -> Client {
+    let cache = CacheConfig {
+        dir: Some(dir.to_path_buf()),
+        ttl: None,
+    };
+    server.builder().cache(cache).build().unwrap()
+}
+
+/// Get the saved etag for an image from the etag index in a cache directory
+///
+/// # Arguments
+///
+/// * `cache` - The cache directory the index is in
+/// * `dest` - The image to get the etag for
+fn saved_etag(cache: &Path, dest: &Path) -> Option<String> {
+    let data = std::fs::read(cache.join("image-etags.json")).ok()?;
+    let index: HashMap<String, String> = serde_json::from_slice(&data).unwrap();
+    index.get(dest.to_str().unwrap()).cloned()
+}
+
+/// Get the names of the files in a directory
+///
+/// # Arguments
+///
+/// * `dir` - The directory to list
+fn files(dir: &Path) -> Vec<String> {
+    let mut names: Vec<_> = std::fs::read_dir(dir)
+        .unwrap()
+        .map(|entry| entry.unwrap().file_name().to_string_lossy().into_owned())
+        .collect();
+    names.sort();
+    names
+}
+
+#[tokio::test]
+async fn download_image_skips_files_of_the_same_size() {
+    let methods = Arc::new(Mutex::new(Vec::new()));
+    let recorded = methods.clone();
+    let server = MockServer::start_with(move |req| {
+        if req.uri().path() != "/3/configuration" {
+            recorded.lock().unwrap().push(req.method().to_string());
+        }
+        let (status, body) = respond(req);
+        json_response(status, body)
+    });
+    let tmdb = server.client();
+    let dir = scratch("download");
+    let dest = dir.join("nested").join("poster.jpg");
+    let first = tmdb
+        .download_image("/abc.jpg", PosterSize::W500, &dest)
+        .await
+        .unwrap();
+    assert!(!first.skipped);
+    assert_eq!(first.path, dest);
+    assert_eq!(
+        std::fs::read_to_string(&dest).unwrap(),
+        "\"/t/p/w500/abc.jpg\""
+    );
+    // the second download should find the same size on disk without a GET
+    let second = tmdb
+        .download_image("/abc.jpg", PosterSize::W500, &dest)
+        .await
+        .unwrap();
+    assert!(second.skipped);
+    assert_eq!(*methods.lock().unwrap(), vec!["GET", "HEAD"]);
+    // only the finished image should be left behind
+    assert_eq!(files(dest.parent().unwrap()), vec!["poster.jpg"]);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn download_image_replaces_files_whose_size_changed() {
+    let server = MockServer::start(respond);
+    let tmdb = server.client();
+    let dir = scratch("resized");
+    let dest = dir.join("poster.jpg");
+    std::fs::create_dir_all(&dir).unwrap();
+    std::fs::write(&dest, "old").unwrap();
+    let download = tmdb
+        .download_image("/abc.jpg", PosterSize::W500, &dest)
+        .await
+        .unwrap();
+    assert!(!download.skipped);
+    assert_eq!(
+        std::fs::read_to_string(&dest).unwrap(),
+        "\"/t/p/w500/abc.jpg\""
+    );
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn download_images_places_relative_paths_in_the_cache_dir() {
+    let server = MockServer::start(respond);
+    let dir = scratch("bulk");
+    let cache = CacheConfig {
+        dir: Some(dir.clone()),
+        ttl: None,
+    };
+    let tmdb = server.builder().cache(cache).build().unwrap();
+    let images = vec![
+        ImageDownload::new("/a.jpg", PosterSize::Original, "a/poster.jpg"),
+        ImageDownload::new("/b.jpg", BackdropSize::W780, "b/fanart.jpg"),
+    ];
+    let downloads = tmdb.download_images(images, 2).await.unwrap();
+    let paths: Vec<_> = downloads.into_iter().map(|res| res.unwrap().path).collect();
+    assert_eq!(
+        paths,
+        vec![dir.join("a/poster.jpg"), dir.join("b/fanart.jpg")]
+    );
+    assert_eq!(
+        std::fs::read_to_string(dir.join("b/fanart.jpg")).unwrap(),
+        "\"/t/p/w780/b.jpg\""
+    );
+    // the configuration should only be fetched once for the whole batch
+    let configs = server
+        .requests()
+        .iter()
+        .filter(|uri| uri.starts_with("/3/configuration"))
+        .count();
+    assert_eq!(configs, 1);
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn download_image_skips_when_tmdb_says_not_modified() {
+    let version = Arc::new(Mutex::new(Version {
+        etag: "\"v1\"",
+        body: "AAAA",
+    }));
+    let not_modified = Arc::new(AtomicUsize::new(0));
+    let server = versioned(version, not_modified.clone());
+    let dir = scratch("etag");
+    let cache = dir.join("cache");
+    let tmdb = cached_client(&server, &cache);
+    let dest = dir.join("media").join("poster.jpg");
+    let first = tmdb
+        .download_image("/abc.jpg", PosterSize::W500, &dest)
+        .await
+        .unwrap();
+    assert!(!first.skipped);
+    assert_eq!(saved_etag(&cache, &dest).as_deref(), Some("\"v1\""));
+    // etags are kept out of the media directory
+    assert_eq!(files(&dir.join("media")), vec!["poster.jpg"]);
+    let second = tmdb
+        .download_image("/abc.jpg", PosterSize::W500, &dest)
+        .await
+        .unwrap();
+    assert!(second.skipped);
+    assert_eq!(not_modified.load(Ordering::SeqCst), 1);
+    assert_eq!(std::fs::read_to_string(&dest).unwrap(), "AAAA");
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn download_image_replaces_images_whose_etag_changed() {
+    let version = Arc::new(Mutex::new(Version {
+        etag: "\"v1\"",
+        body: "AAAA",
+    }));
+    let not_modified = Arc::new(AtomicUsize::new(0));
+    let server = versioned(version.clone(), not_modified.clone());
+    let dir = scratch("changed");
+    let cache = dir.join("cache");
+    let tmdb = cached_client(&server, &cache);
+    let dest = dir.join("media").join("poster.jpg");
+    tmdb.download_image("/abc.jpg", PosterSize::W500, &dest)
+        .await
+        .unwrap();
+    // a new image of the same size must still replace the old one
+    *version.lock().unwrap() = Version {
+        etag: "\"v2\"",
+        body: "BBBB",
+    };
+    let second = tmdb
+        .download_image("/abc.jpg", PosterSize::W500, &dest)
+        .await
+        .unwrap();
+    assert!(!second.skipped);
+    assert_eq!(not_modified.load(Ordering::SeqCst), 0);
+    assert_eq!(std::fs::read_to_string(&dest).unwrap(), "BBBB");
+    assert_eq!(saved_etag(&cache, &dest).as_deref(), Some("\"v2\""));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
+
+#[tokio::test]
+async fn image_downloads_skip_the_rate_limiter() {
+    let server = MockServer::start(respond);
+    // only allow the configuration request through the limiter
+    let limiter = RateLimiter::new(1, Duration::from_secs(3600)).nonblocking();
+    let tmdb = server.builder().rate_limiter(limiter).build().unwrap();
+    let dir = scratch("unlimited");
+    let images = vec![
+        ImageDownload::new("/a.jpg", PosterSize::W500, dir.join("a.jpg")),
+        ImageDownload::new("/b.jpg", PosterSize::W500, dir.join("b.jpg")),
+        ImageDownload::new("/c.jpg", PosterSize::W500, dir.join("c.jpg")),
+    ];
+    let downloads = tmdb.download_images(images, 3).await.unwrap();
+    assert!(downloads.iter().all(|download| download.is_ok()));
+    std::fs::remove_dir_all(&dir).unwrap();
+}
@@ -0,0 +1,90 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use hyper::{Body, Response};
+use serde_json::json;
+use std::time::Duration;
+use tmdb_cli::Error;
+
+use common::MockServer;
+
+/// Get the error tmdb returns for a request when it responds with a status and body
+///
+/// # Arguments
+///
+/// * `status` - The status code to respond with
+/// * `body` - The json body to respond with
+async fn error_for(status: u16, body: serde_json::Value) -> Error {
+    let server = MockServer::start(move |_| (status, body.clone()));
+    let tmdb = server.client();
+    tmdb.movies.watch_providers(157336).await.unwrap_err()
+}
+
+#[tokio::test]
+async fn tmdb_error_bodies_are_typed() {
+    let body = json!({"status_code": 34, "status_message": "The resource you requested could not be found."});
+    match error_for(404, body).await {
+        Error::NotFound { message } => {
+            assert_eq!(message, "The resource you requested could not be found.")
+        }
+        err => panic!("expected NotFound but got {:?}", err),
+    }
+    let body = json!({"status_code": 7, "status_message": "Invalid API key: You must be granted a valid key."});
+    match error_for(401, body).await {
+        Error::Unauthorized { tmdb_code, message } => {
+            assert_eq!(tmdb_code, Some(7));
+            assert!(message.starts_with("Invalid API key"));
+        }
+        err => panic!("expected Unauthorized but got {:?}", err),
+    }
+    // errors without a dedicated variant keep their status
+    match error_for(500, json!({"status_code": 11})).await {
+        Error::Api {
+            status,
+            tmdb_code,
+            message,
+        } => {
+            assert_eq!((status, tmdb_code), (500, Some(11)));
+            assert_eq!(message, "500 Internal Server Error");
+        }
+        err => panic!("expected Api but got {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn rate_limits_keep_retry_after() {
+    let server = MockServer::start_with(|_| {
+        Response::builder()
+            .status(429)
+            .header("retry-after", "5")
+            .body(Body::from(r#"{"status_code": 25}"#))
+            .unwrap()
+    });
+    let err = server.client().movies.watch_providers(157336).await;
+    match err {
+        Err(Error::RateLimited { retry_after }) => {
+            assert_eq!(retry_after, Some(Duration::from_secs(5)))
+        }
+        err => panic!("expected RateLimited but got {:?}", err),
+    }
+}
+
+#[tokio::test]
+async fn bad_bodies_report_the_field_that_failed() {
+    let server = MockServer::start(|_| {
+        let body = json!({
+            "id": 157336,
+            "results": {"US": {"flatrate": [{"provider_id": "eight", "provider_name": "Netflix"}]}}
+        });
+        (200, body)
+    });
+    let err = server.client().movies.watch_providers(157336).await;
+    match err {
+        Err(Error::Deserialize { path, snippet, .. }) => {
+            assert_eq!(path, "results.US.flatrate[0].provider_id");
+            assert!(snippet.contains("eight"));
+        }
+        err => panic!("expected Deserialize but got {:?}", err),
+    }
+}
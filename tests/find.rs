@@ -0,0 +1,107 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+use tmdb_cli::models::ExternalSource;
+
+use common::{movie, query_param, MockServer};
+
+#[tokio::test]
+async fn find_maps_external_ids_to_tmdb_ids() {
+    let server = MockServer::start(|_| {
+        let season = json!({
+            "air_date": null,
+            "id": 3624,
+            "name": "Season 1",
+            "overview": "",
+            "poster_path": null,
+            "season_number": 1,
+            "show_id": 1399
+        });
+        let body = json!({
+            "movie_results": [movie(157336)],
+            "person_results": [],
+            "tv_results": [],
+            "tv_episode_results": [],
+            "tv_season_results": [season]
+        });
+        (200, body)
+    });
+    let tmdb = server.client();
+    let found = tmdb
+        .find("tt0816692", ExternalSource::ImdbId)
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/find/tt0816692?"));
+    assert_eq!(
+        query_param(uri, "external_source").as_deref(),
+        Some("imdb_id")
+    );
+    assert_eq!(found.movie_results[0].id, 157336);
+    assert_eq!(found.tv_season_results[0].show_id, Some(1399));
+    assert!(found.tv_results.is_empty());
+}
+
+#[tokio::test]
+async fn external_ids_are_fetched_for_movies_shows_and_people() {
+    let server = MockServer::start(|req| {
+        let body = match req.uri().path() {
+            "/3/movie/157336/external_ids" => json!({
+                "id": 157336,
+                "imdb_id": "tt0816692",
+                "wikidata_id": "Q13417189",
+                "facebook_id": null,
+                "instagram_id": null,
+                "twitter_id": null
+            }),
+            "/3/tv/1399/external_ids" => json!({
+                "id": 1399,
+                "imdb_id": "tt0944947",
+                "freebase_mid": "/m/0524b41",
+                "tvdb_id": 121361,
+                "tvrage_id": null
+            }),
+            "/3/person/287/external_ids" => json!({
+                "id": 287,
+                "imdb_id": "nm0000093",
+                "tiktok_id": null,
+                "youtube_id": null,
+                "instagram_id": "bradpitt"
+            }),
+            path => panic!("unexpected request to {}", path),
+        };
+        (200, body)
+    });
+    let tmdb = server.client();
+    let movie = tmdb.movies.external_ids(157336).await.unwrap();
+    assert_eq!(movie.id, Some(157336));
+    assert_eq!(movie.imdb_id.as_deref(), Some("tt0816692"));
+    assert_eq!(movie.wikidata_id.as_deref(), Some("Q13417189"));
+    assert!(movie.facebook_id.is_none());
+    let show = tmdb.tv.external_ids(1399).await.unwrap();
+    assert_eq!(show.tvdb_id, Some(121361));
+    assert_eq!(show.freebase_mid.as_deref(), Some("/m/0524b41"));
+    assert!(show.tvrage_id.is_none());
+    let person = tmdb.people.external_ids(287).await.unwrap();
+    assert_eq!(person.instagram_id.as_deref(), Some("bradpitt"));
+    // ids tmdb doesn't send at all are treated like nulls
+    assert!(person.tiktok_id.is_none() && person.twitter_id.is_none());
+    assert_eq!(server.requests().len(), 3);
+}
+
+#[tokio::test]
+async fn find_escapes_ids_into_a_single_path_segment() {
+    let server = MockServer::start(|_| (200, json!({})));
+    let tmdb = server.client();
+    tmdb.find("we/are?#1 fans", ExternalSource::TwitterId)
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/find/we%2Fare%3F%231%20fans?"));
+    assert_eq!(
+        query_param(uri, "external_source").as_deref(),
+        Some("twitter_id")
+    );
+}
@@ -0,0 +1,59 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+use std::time::{Duration, Instant};
+use tmdb_cli::{Error, RateLimiter};
+
+use common::MockServer;
+
+#[tokio::test]
+async fn bursts_over_capacity_are_delayed() {
+    let server = MockServer::start(|_| (200, json!({"id": 157336, "results": {}})));
+    // allow 2 requests and then 1 more every 250ms
+    let limiter = RateLimiter::new(2, Duration::from_millis(500));
+    let tmdb = server.builder().rate_limiter(limiter).build().unwrap();
+    let start = Instant::now();
+    for _ in 0..2 {
+        tmdb.movies.watch_providers(157336).await.unwrap();
+    }
+    // the first burst fits in our bucket so it should not wait
+    assert!(start.elapsed() < Duration::from_millis(200));
+    for _ in 0..2 {
+        tmdb.movies.watch_providers(157336).await.unwrap();
+    }
+    assert!(start.elapsed() >= Duration::from_millis(450));
+    assert_eq!(server.requests().len(), 4);
+}
+
+#[test]
+fn try_acquire_fails_without_blocking_when_empty() {
+    let limiter = RateLimiter::new(2, Duration::from_secs(60));
+    assert!(limiter.try_acquire().is_ok());
+    // clones share a bucket
+    assert!(limiter.clone().try_acquire().is_ok());
+    let start = Instant::now();
+    let wait = limiter.try_acquire().unwrap_err();
+    assert!(start.elapsed() < Duration::from_millis(50));
+    assert!(wait > Duration::from_secs(29) && wait <= Duration::from_secs(30));
+}
+
+#[tokio::test]
+async fn nonblocking_limiters_fail_requests_instead_of_waiting() {
+    let server = MockServer::start(|_| (200, json!({"id": 157336, "results": {}})));
+    let limiter = RateLimiter::new(1, Duration::from_secs(60)).nonblocking();
+    let tmdb = server.builder().rate_limiter(limiter).build().unwrap();
+    tmdb.movies.watch_providers(157336).await.unwrap();
+    let start = Instant::now();
+    let err = tmdb.movies.watch_providers(157336).await;
+    assert!(start.elapsed() < Duration::from_millis(200));
+    assert!(matches!(
+        err,
+        Err(Error::RateLimited {
+            retry_after: Some(_)
+        })
+    ));
+    // the limited request never reached tmdb
+    assert_eq!(server.requests().len(), 1);
+}
@@ -0,0 +1,77 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+use tmdb_cli::models::{CombinedCastCredit, CombinedCrewCredit};
+
+use common::{movie, page, person, show, MockServer};
+
+#[tokio::test]
+async fn person_details_allow_missing_dates() {
+    let server = MockServer::start(|_| {
+        let mut body = person(865);
+        body["also_known_as"] = json!(["Someone Else"]);
+        body["biography"] = json!("");
+        body["birthday"] = json!("1965-02-17");
+        body["deathday"] = json!(null);
+        body["place_of_birth"] = json!("");
+        (200, body)
+    });
+    let tmdb = server.client();
+    let details = tmdb.people.details(865).await.unwrap();
+    assert_eq!(
+        server.requests()[0].split('?').next(),
+        Some("/3/person/865")
+    );
+    assert_eq!(details.also_known_as, vec!["Someone Else"]);
+    assert_eq!(
+        details.birthday.map(|date| date.to_string()).as_deref(),
+        Some("1965-02-17")
+    );
+    assert!(details.deathday.is_none());
+}
+
+#[tokio::test]
+async fn combined_credits_split_on_media_type() {
+    let server = MockServer::start(|_| {
+        let mut acted = movie(1);
+        acted["media_type"] = json!("movie");
+        acted["character"] = json!("Himself");
+        acted["credit_id"] = json!("a");
+        acted["release_date"] = json!("");
+        let mut directed = show(2);
+        directed["media_type"] = json!("tv");
+        directed["credit_id"] = json!("b");
+        directed["department"] = json!("Directing");
+        directed["job"] = json!("Director");
+        (200, json!({"id": 865, "cast": [acted], "crew": [directed]}))
+    });
+    let tmdb = server.client();
+    let credits = tmdb.people.combined_credits(865).await.unwrap();
+    match &credits.cast[0] {
+        CombinedCastCredit::Movie(role) => {
+            assert_eq!(role.movie.id, 1);
+            assert!(role.movie.release_date.is_none());
+        }
+        other => panic!("expected a movie credit but got {:?}", other),
+    }
+    match &credits.crew[0] {
+        CombinedCrewCredit::Tv(job) => assert_eq!(job.job, "Director"),
+        other => panic!("expected a tv credit but got {:?}", other),
+    }
+}
+
+#[tokio::test]
+async fn person_search_sends_include_adult() {
+    let server = MockServer::start(|req| page(req, 1, person));
+    let tmdb = server.client();
+    let cursor = tmdb.people.search("Michael Bay").exec().await.unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/search/person?"));
+    assert_eq!(
+        common::query_param(uri, "include_adult").as_deref(),
+        Some("false")
+    );
+    assert_eq!(cursor.results[0].id, 100);
+}
@@ -0,0 +1,142 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+
+use common::{query_param, MockServer};
+
+/// Builds a watch provider for a mock response
+///
+/// # Arguments
+///
+/// * `id` - The id of this provider
+/// * `name` - The name of this provider
+fn provider(id: i64, name: &str) -> serde_json::Value {
+    json!({
+        "logo_path": "/logo.jpg",
+        "provider_id": id,
+        "provider_name": name,
+        "display_priority": 1
+    })
+}
+
+#[tokio::test]
+async fn watch_providers_are_keyed_by_country() {
+    let server = MockServer::start(|_| {
+        let body = json!({
+            "id": 157336,
+            "results": {
+                "US": {
+                    "link": "https://www.themoviedb.org/movie/157336/watch?locale=US",
+                    "flatrate": [provider(8, "Netflix")],
+                    "rent": [provider(2, "Apple TV")],
+                    "buy": [provider(2, "Apple TV"), provider(3, "Google Play Movies")]
+                },
+                "DE": {
+                    "ads": [provider(300, "Pluto TV")],
+                    "free": [provider(1, "Free Stream")]
+                }
+            }
+        });
+        (200, body)
+    });
+    let tmdb = server.client();
+    let providers = tmdb.movies.watch_providers(157336).await.unwrap();
+    assert_eq!(
+        server.requests()[0].split('?').next(),
+        Some("/3/movie/157336/watch/providers")
+    );
+    let us = &providers["US"];
+    assert_eq!(us.flatrate[0].provider_name, "Netflix");
+    assert_eq!(us.buy.len(), 2);
+    assert!(us.ads.is_empty());
+    let de = &providers["DE"];
+    assert!(de.link.is_none());
+    assert_eq!(de.ads[0].provider_id, 300);
+    assert_eq!(de.free[0].provider_id, 1);
+}
+
+#[tokio::test]
+async fn provider_and_region_lists() {
+    let server = MockServer::start(|req| {
+        let body = if req.uri().path().ends_with("/regions") {
+            json!({
+                "results": [
+                    {"iso_3166_1": "US", "english_name": "United States of America", "native_name": "United States"}
+                ]
+            })
+        } else {
+            let mut netflix = provider(8, "Netflix");
+            netflix["display_priorities"] = json!({"US": 0, "DE": 2});
+            json!({ "results": [netflix] })
+        };
+        (200, body)
+    });
+    let tmdb = server.client();
+    let movie = tmdb.providers.movie().exec().await.unwrap();
+    let tv = tmdb.providers.tv().exec().await.unwrap();
+    let regions = tmdb.providers.regions().await.unwrap();
+    let paths: Vec<_> = server
+        .requests()
+        .iter()
+        .map(|uri| uri.split('?').next().unwrap().to_owned())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/3/watch/providers/movie",
+            "/3/watch/providers/tv",
+            "/3/watch/providers/regions"
+        ]
+    );
+    assert_eq!(movie[0].display_priorities["DE"], 2);
+    assert_eq!(tv[0].provider_name, "Netflix");
+    assert_eq!(regions[0].iso_3166_1, "US");
+}
+
+#[tokio::test]
+async fn provider_lists_use_the_default_language_and_watch_region() {
+    let server = MockServer::start(|_| (200, json!({ "results": [provider(8, "Netflix")] })));
+    let tmdb = server
+        .builder()
+        .language("de-DE")
+        .region("DE")
+        .build()
+        .unwrap();
+    tmdb.providers.movie().exec().await.unwrap();
+    tmdb.providers.tv().exec().await.unwrap();
+    for uri in server.requests() {
+        assert_eq!(query_param(&uri, "language").as_deref(), Some("de-DE"));
+        assert_eq!(query_param(&uri, "watch_region").as_deref(), Some("DE"));
+        assert_eq!(query_param(&uri, "region"), None);
+    }
+    // neither is sent without client defaults
+    let server = MockServer::start(|_| (200, json!({ "results": [] })));
+    server.client().providers.movie().exec().await.unwrap();
+    let uri = &server.requests()[0];
+    assert_eq!(query_param(uri, "language"), None);
+    assert_eq!(query_param(uri, "watch_region"), None);
+}
+
+#[tokio::test]
+async fn provider_lists_can_be_requested_for_another_region() {
+    let server = MockServer::start(|_| (200, json!({ "results": [provider(8, "Netflix")] })));
+    let tmdb = server
+        .builder()
+        .language("en-US")
+        .region("US")
+        .build()
+        .unwrap();
+    tmdb.providers
+        .tv()
+        .watch_region("DE")
+        .language("de-DE")
+        .exec()
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert_eq!(query_param(uri, "watch_region").as_deref(), Some("DE"));
+    assert_eq!(query_param(uri, "language").as_deref(), Some("de-DE"));
+    assert_eq!(uri.matches("language=").count(), 1);
+}
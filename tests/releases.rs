@@ -0,0 +1,97 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+use tmdb_cli::models::ReleaseType;
+
+use common::MockServer;
+
+#[tokio::test]
+async fn release_dates_pick_the_theatrical_certification() {
+    let server = MockServer::start(|_| {
+        let body = json!({
+            "id": 157336,
+            "results": [
+                {
+                    "iso_3166_1": "US",
+                    "release_dates": [
+                        {"certification": "", "iso_639_1": "", "note": "Los Angeles", "release_date": "2014-10-26T00:00:00.000Z", "type": 1},
+                        {"certification": "NR", "iso_639_1": "", "note": "", "release_date": "2015-03-31T00:00:00.000Z", "type": 4},
+                        {"certification": "PG-13", "iso_639_1": "", "note": "", "release_date": "2014-11-05T00:00:00.000Z", "type": 3}
+                    ]
+                },
+                {
+                    "iso_3166_1": "DE",
+                    "release_dates": [
+                        {"certification": "12", "iso_639_1": "", "note": "", "release_date": "2014-11-06T00:00:00.000Z", "type": 9}
+                    ]
+                }
+            ]
+        });
+        (200, body)
+    });
+    let tmdb = server.client();
+    let releases = tmdb.movies.release_dates(157336).await.unwrap();
+    assert_eq!(
+        server.requests()[0].split('?').next(),
+        Some("/3/movie/157336/release_dates")
+    );
+    let us = releases.country("US").unwrap();
+    assert_eq!(us.release_dates[0]._type, ReleaseType::Premiere);
+    assert_eq!(us.release_dates[1]._type, ReleaseType::Digital);
+    assert_eq!(releases.certification("US"), Some("PG-13"));
+    // unknown release types should still parse
+    assert_eq!(
+        releases.country("DE").unwrap().release_dates[0]._type,
+        ReleaseType::Other(9)
+    );
+    assert_eq!(releases.certification("DE"), Some("12"));
+    assert_eq!(releases.certification("FR"), None);
+}
+
+#[tokio::test]
+async fn content_ratings_and_certification_lists() {
+    let server = MockServer::start(|req| {
+        let body = if req.uri().path().starts_with("/3/certification/") {
+            json!({
+                "certifications": {
+                    "US": [
+                        {"certification": "TV-14", "meaning": "", "order": 5},
+                        {"certification": "TV-MA", "meaning": "", "order": 6}
+                    ]
+                }
+            })
+        } else {
+            json!({
+                "id": 1399,
+                "results": [
+                    {"descriptors": [], "iso_3166_1": "DE", "rating": "16"},
+                    {"descriptors": [], "iso_3166_1": "US", "rating": "TV-MA"}
+                ]
+            })
+        };
+        (200, body)
+    });
+    let tmdb = server.client();
+    let ratings = tmdb.tv.content_ratings(1399).await.unwrap();
+    assert_eq!(ratings.certification("US"), Some("TV-MA"));
+    assert_eq!(ratings.certification("GB"), None);
+    let movie = tmdb.certifications.movie().await.unwrap();
+    let tv = tmdb.certifications.tv().await.unwrap();
+    assert_eq!(movie["US"][1].order, 6);
+    assert_eq!(tv["US"][0].certification, "TV-14");
+    let paths: Vec<_> = server
+        .requests()
+        .iter()
+        .map(|uri| uri.split('?').next().unwrap().to_owned())
+        .collect();
+    assert_eq!(
+        paths,
+        vec![
+            "/3/tv/1399/content_ratings",
+            "/3/certification/movie/list",
+            "/3/certification/tv/list"
+        ]
+    );
+}
@@ -0,0 +1,117 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use hyper::{Body, Response};
+use serde_json::json;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Arc;
+use std::time::{Duration, Instant};
+use tmdb_cli::{Client, Error, RetryPolicy};
+
+use common::{json_response, MockServer};
+
+/// Starts a mock server that fails requests until it has been hit enough times
+///
+/// # Arguments
+///
+/// * `failures` - The number of requests to fail before succeeding
+/// * `fail` - Builds the response to fail each request with
+fn flaky<F>(failures: usize, fail: F) -> (MockServer, Arc<AtomicUsize>)
+where
+    F: Fn() -> Response<Body> + Send + Sync + 'static,
+{
+    let hits = Arc::new(AtomicUsize::new(0));
+    let counter = hits.clone();
+    let server = MockServer::start_with(move |_| {
+        if counter.fetch_add(1, Ordering::SeqCst) < failures {
+            fail()
+        } else {
+            json_response(200, json!({"id": 157336, "results": {}}))
+        }
+    });
+    (server, hits)
+}
+
+/// Builds a client that retries quickly
+///
+/// # Arguments
+///
+/// * `server` - The server to talk to
+/// * `max_attempts` - The max number of times to send a request
+fn client(server: &MockServer, max_attempts: u32) -> Client {
+    let retry = RetryPolicy::new(max_attempts)
+        .base_delay(Duration::from_millis(1))
+        .max_delay(Duration::from_millis(5));
+    server.builder().retry(retry).build().unwrap()
+}
+
+#[tokio::test]
+async fn server_errors_are_retried_until_they_succeed() {
+    let (server, hits) = flaky(2, || json_response(503, json!({"status_code": 11})));
+    let providers = client(&server, 3).movies.watch_providers(157336).await;
+    assert!(providers.is_ok());
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
+
+#[tokio::test]
+async fn client_errors_are_not_retried() {
+    let (server, hits) = flaky(usize::MAX, || {
+        let body = json!({"status_code": 34, "status_message": "not found"});
+        json_response(404, body)
+    });
+    let err = client(&server, 3).movies.watch_providers(0).await;
+    assert!(matches!(err, Err(Error::NotFound { .. })));
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+}
+
+#[tokio::test]
+async fn rate_limited_requests_wait_for_retry_after() {
+    let (server, hits) = flaky(1, || {
+        Response::builder()
+            .status(429)
+            .header("retry-after", "1")
+            .body(Body::empty())
+            .unwrap()
+    });
+    let retry = RetryPolicy::new(2)
+        .base_delay(Duration::from_millis(1))
+        .max_delay(Duration::from_secs(2));
+    let tmdb = server.builder().retry(retry).build().unwrap();
+    let start = Instant::now();
+    let providers = tmdb.movies.watch_providers(157336).await;
+    assert!(providers.is_ok());
+    assert_eq!(hits.load(Ordering::SeqCst), 2);
+    // our own backoff would have been far shorter than what tmdb asked for
+    assert!(start.elapsed() >= Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn retry_after_longer_than_max_delay_is_returned() {
+    let (server, hits) = flaky(usize::MAX, || {
+        Response::builder()
+            .status(429)
+            .header("retry-after", "3600")
+            .body(Body::empty())
+            .unwrap()
+    });
+    let start = Instant::now();
+    let err = client(&server, 3).movies.watch_providers(157336).await;
+    match err {
+        Err(Error::RateLimited { retry_after }) => {
+            assert_eq!(retry_after, Some(Duration::from_secs(3600)))
+        }
+        err => panic!("expected RateLimited but got {:?}", err),
+    }
+    // the caller gets the error right away instead of waiting an hour
+    assert_eq!(hits.load(Ordering::SeqCst), 1);
+    assert!(start.elapsed() < Duration::from_secs(1));
+}
+
+#[tokio::test]
+async fn retries_stop_after_max_attempts() {
+    let (server, hits) = flaky(usize::MAX, || json_response(503, json!({})));
+    let err = client(&server, 3).movies.watch_providers(157336).await;
+    assert!(matches!(err, Err(Error::Api { status: 503, .. })));
+    assert_eq!(hits.load(Ordering::SeqCst), 3);
+}
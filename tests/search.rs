@@ -0,0 +1,55 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+use tmdb_cli::models::MediaItem;
+
+use common::{movie, person, query_param, show, MockServer};
+
+#[tokio::test]
+async fn multi_search_describes_every_media_type() {
+    let server = MockServer::start(|_| {
+        let mut found_movie = movie(1);
+        found_movie["media_type"] = json!("movie");
+        found_movie["poster_path"] = json!("/movie.jpg");
+        let mut found_show = show(2);
+        found_show["media_type"] = json!("tv");
+        let mut found_person = person(3);
+        found_person["media_type"] = json!("person");
+        found_person["profile_path"] = json!("/person.jpg");
+        let body = json!({
+            "page": 1,
+            "results": [found_movie, found_show, found_person],
+            "total_pages": 1,
+            "total_results": 3
+        });
+        (200, body)
+    });
+    let tmdb = server.client();
+    let search = tmdb
+        .search_multi("Red")
+        .adult()
+        .region("US")
+        .exec()
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/search/multi?"));
+    assert_eq!(query_param(uri, "include_adult").as_deref(), Some("true"));
+    assert_eq!(query_param(uri, "region").as_deref(), Some("US"));
+    let described: Vec<_> = search
+        .results
+        .iter()
+        .map(|item| (item.title(), item.year(), item.poster_path()))
+        .collect();
+    assert_eq!(
+        described,
+        vec![
+            ("Movie 1", Some(2016), Some("/movie.jpg")),
+            ("Show 2", Some(2003), None),
+            ("Person 3", None, Some("/person.jpg")),
+        ]
+    );
+    assert!(matches!(search.results[2], MediaItem::Person(_)));
+}
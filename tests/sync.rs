@@ -0,0 +1,48 @@
+#![cfg(feature = "sync")]
+
+mod common;
+
+use tokio::runtime::Runtime;
+
+use common::{movie, page, MockServer};
+
+/// Starts a mock server on a runtime whose worker threads keep serving it while
+/// the test blocks on the sync api
+///
+/// # Arguments
+///
+/// * `total_pages` - The number of pages of movies to serve
+fn serve(total_pages: u64) -> (Runtime, MockServer) {
+    let runtime = tokio::runtime::Builder::new()
+        .threaded_scheduler()
+        .enable_all()
+        .build()
+        .unwrap();
+    let server = runtime.enter(|| MockServer::start(move |req| page(req, total_pages, movie)));
+    (runtime, server)
+}
+
+#[test]
+fn cursors_iterate_across_pages() {
+    let (_runtime, server) = serve(3);
+    let tmdb = server.client();
+    let ids: Vec<i64> = tmdb
+        .movies
+        .popular()
+        .into_iter()
+        .map(|movie| movie.unwrap().id)
+        .collect();
+    assert_eq!(server.pages(), vec![1, 2, 3]);
+    // every page should have been read exactly once and in order
+    assert_eq!(ids, vec![100, 101, 200, 201, 300, 301]);
+}
+
+#[test]
+fn iteration_stops_at_max_items_without_loading_more_pages() {
+    let (_runtime, server) = serve(10);
+    let tmdb = server.client();
+    let movies: Vec<_> = tmdb.movies.popular().max_items(3).into_iter().collect();
+    assert_eq!(movies.len(), 3);
+    assert!(movies.iter().all(Result::is_ok));
+    assert_eq!(server.pages(), vec![1, 2]);
+}
@@ -0,0 +1,48 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+use tmdb_cli::models::MediaItem;
+use tmdb_cli::{MediaType, TimeWindow};
+
+use common::{movie, person, show, MockServer};
+
+#[tokio::test]
+async fn trending_all_yields_mixed_media() {
+    let server = MockServer::start(|_| {
+        let mut hot_movie = movie(1);
+        hot_movie["media_type"] = json!("movie");
+        let mut hot_show = show(2);
+        hot_show["media_type"] = json!("tv");
+        let mut hot_person = person(3);
+        hot_person["media_type"] = json!("person");
+        hot_person["known_for"] = json!([hot_movie.clone()]);
+        let body = json!({
+            "page": 1,
+            "results": [hot_movie, hot_show, hot_person],
+            "total_pages": 1,
+            "total_results": 3
+        });
+        (200, body)
+    });
+    let tmdb = server.client();
+    let trending = tmdb
+        .trending
+        .list(MediaType::All, TimeWindow::Week)
+        .exec()
+        .await
+        .unwrap();
+    assert_eq!(
+        server.requests()[0].split('?').next(),
+        Some("/3/trending/all/week")
+    );
+    assert!(matches!(&trending.results[0], MediaItem::Movie(movie) if movie.id == 1));
+    assert!(matches!(&trending.results[1], MediaItem::Show(show) if show.id == 2));
+    match &trending.results[2] {
+        MediaItem::Person(person) => {
+            assert!(matches!(&person.known_for[0], MediaItem::Movie(movie) if movie.id == 1))
+        }
+        other => panic!("expected a person but got {:?}", other),
+    }
+}
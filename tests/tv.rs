@@ -0,0 +1,104 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::{json, Value};
+
+use common::MockServer;
+
+/// Builds an episode for a mock response
+///
+/// # Arguments
+///
+/// * `number` - The number of this episode in its season
+/// * `air_date` - When this episode aired
+fn episode(number: u64, air_date: Value) -> Value {
+    json!({
+        "air_date": air_date,
+        "episode_number": number,
+        "id": 1000 + number,
+        "name": format!("Episode {}", number),
+        "overview": "",
+        "production_code": "",
+        "runtime": 60,
+        "season_number": 1,
+        "still_path": null,
+        "vote_average": 1.0,
+        "vote_count": 1
+    })
+}
+
+#[tokio::test]
+async fn season_lists_episodes_with_air_dates() {
+    let server = MockServer::start(|_| {
+        let body = json!({
+            "_id": "abc",
+            "air_date": "2011-04-17",
+            "episodes": [episode(1, json!("2011-04-17")), episode(2, json!(""))],
+            "id": 3624,
+            "name": "Season 1",
+            "overview": "",
+            "poster_path": null,
+            "season_number": 1
+        });
+        (200, body)
+    });
+    let tmdb = server.client();
+    let season = tmdb.tv.season(1399, 1).await.unwrap();
+    assert_eq!(
+        server.requests()[0].split('?').next(),
+        Some("/3/tv/1399/season/1")
+    );
+    assert_eq!(season.episodes.len(), 2);
+    assert_eq!(
+        season.episodes[0]
+            .air_date
+            .map(|date| date.to_string())
+            .as_deref(),
+        Some("2011-04-17")
+    );
+    assert!(season.episodes[1].air_date.is_none());
+}
+
+#[tokio::test]
+async fn episode_includes_guest_stars_and_crew() {
+    let server = MockServer::start(|_| {
+        let mut body = episode(1, json!("2011-04-17"));
+        body["guest_stars"] = json!([{
+            "adult": false,
+            "gender": 2,
+            "id": 1,
+            "known_for_department": "Acting",
+            "name": "Guest",
+            "original_name": "Guest",
+            "popularity": 1.0,
+            "profile_path": null,
+            "character": "Someone",
+            "credit_id": "a",
+            "order": 0
+        }]);
+        body["crew"] = json!([{
+            "adult": false,
+            "gender": 2,
+            "id": 2,
+            "known_for_department": "Directing",
+            "name": "Director",
+            "original_name": "Director",
+            "popularity": 1.0,
+            "profile_path": null,
+            "credit_id": "b",
+            "department": "Directing",
+            "job": "Director"
+        }]);
+        (200, body)
+    });
+    let tmdb = server.client();
+    let details = tmdb.tv.episode(1399, 1, 1).await.unwrap();
+    assert_eq!(
+        server.requests()[0].split('?').next(),
+        Some("/3/tv/1399/season/1/episode/1")
+    );
+    assert_eq!(details.episode.name, "Episode 1");
+    assert_eq!(details.guest_stars[0].character, "Someone");
+    assert_eq!(details.crew[0].job, "Director");
+}
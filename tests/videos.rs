@@ -0,0 +1,78 @@
+#![cfg(not(feature = "sync"))]
+
+mod common;
+
+use serde_json::json;
+use tmdb_cli::models::{Video, VideoSite, VideoType};
+
+use common::{query_param, MockServer};
+
+/// Builds a video for a mock response
+///
+/// # Arguments
+///
+/// * `key` - The key for this video
+/// * `lang` - The language this video is in
+/// * `kind` - What type of video this is
+/// * `official` - Whether this video is official
+/// * `published` - When this video was published
+fn video(key: &str, lang: &str, kind: &str, official: bool, published: &str) -> serde_json::Value {
+    json!({
+        "id": key,
+        "iso_639_1": lang,
+        "iso_3166_1": "US",
+        "key": key,
+        "name": key,
+        "site": "YouTube",
+        "size": 1080,
+        "type": kind,
+        "official": official,
+        "published_at": published
+    })
+}
+
+#[tokio::test]
+async fn videos_are_typed_and_filtered_by_language() {
+    let server = MockServer::start(|_| {
+        let body = json!({
+            "id": 157336,
+            "results": [
+                video("teaser", "en", "Teaser", true, "2014-10-01T00:00:00.000Z"),
+                video("old", "en", "Trailer", true, "2014-05-01T00:00:00.000Z"),
+                video("fan", "en", "Trailer", false, "2014-12-01T00:00:00.000Z"),
+                video("new", "en", "Trailer", true, "2014-10-07T17:00:00.000Z"),
+                video("german", "de", "Trailer", true, "2014-11-01T00:00:00.000Z"),
+                video("bts", "en", "Behind the Scenes", true, "2014-11-01T00:00:00.000Z"),
+            ]
+        });
+        (200, body)
+    });
+    let tmdb = server.client();
+    let videos = tmdb
+        .movies
+        .videos_in_languages(157336, &["en", "null"])
+        .await
+        .unwrap();
+    let uri = &server.requests()[0];
+    assert!(uri.starts_with("/3/movie/157336/videos?"));
+    assert_eq!(
+        query_param(uri, "include_video_language").as_deref(),
+        Some("en%2Cnull")
+    );
+    assert_eq!(videos[0].site, VideoSite::YouTube);
+    assert_eq!(videos[5]._type, VideoType::BehindTheScenes);
+    assert_eq!(
+        videos[0].url().as_deref(),
+        Some("https://www.youtube.com/watch?v=teaser")
+    );
+    // the newest official trailer in our preferred language should win
+    let best = Video::best_trailer(&videos, &["en"]).unwrap();
+    assert_eq!(best.key, "new");
+    let best = Video::best_trailer(&videos, &["de", "en"]).unwrap();
+    assert_eq!(best.key, "german");
+    // plain video requests should not filter by language
+    tmdb.tv.videos(1399).await.unwrap();
+    let uri = &server.requests()[1];
+    assert!(uri.starts_with("/3/tv/1399/videos?"));
+    assert_eq!(query_param(uri, "include_video_language"), None);
+}